@@ -0,0 +1,39 @@
+use soroban_sdk::{contract, contractimpl, Bytes, BytesN, Env};
+use stellar_accounts::verifiers::{utils::extract_from_bytes, Verifier};
+
+#[contract]
+pub struct Ed25519Verifier;
+
+#[contractimpl]
+impl Verifier for Ed25519Verifier {
+    type KeyData = Bytes;
+    type SigData = Bytes;
+
+    /// Verify an Ed25519 signature against a message and public key.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature_payload` - The message hash that was signed
+    /// * `key_data` - 32-byte Ed25519 public key
+    /// * `sig_data` - 64-byte Ed25519 signature over `signature_payload`
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the signature is valid (an invalid signature traps in the
+    ///   host instead of returning)
+    fn verify(
+        e: &Env,
+        signature_payload: Bytes,
+        key_data: Self::KeyData,
+        sig_data: Self::SigData,
+    ) -> bool {
+        let public_key: BytesN<32> =
+            extract_from_bytes(e, &key_data, 0..32).expect("32-byte public key to be extracted");
+        let signature: BytesN<64> =
+            extract_from_bytes(e, &sig_data, 0..64).expect("64-byte signature to be extracted");
+
+        e.crypto()
+            .ed25519_verify(&public_key, &signature_payload, &signature);
+        true
+    }
+}
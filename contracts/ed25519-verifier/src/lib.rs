@@ -0,0 +1,4 @@
+#![no_std]
+#![allow(dead_code)]
+
+mod contract;
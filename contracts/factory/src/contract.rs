@@ -1,18 +1,82 @@
 use soroban_sdk::{
-    contract, contractimpl, deploy::DeployerWithAddress, Address, Bytes, BytesN, Env,
+    contract, contractclient, contractimpl, deploy::DeployerWithAddress, panic_with_error,
+    symbol_short, token::TokenClient, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Map,
+    Symbol, Vec,
 };
-use soroban_sdk_tools::{contractstorage, InstanceItem};
-use stellar_accounts::smart_account::Signer;
+use soroban_sdk_tools::{contractstorage, InstanceItem, PersistentMap};
+use stellar_accounts::smart_account::{ContextRule, ContextRuleType, Signer};
 
-use crate::xlm;
+use crate::{
+    error::FactoryError,
+    events::{AccountCreated, GiftClaimed, GiftDeposited, GiftRefunded},
+    funding, gift, registry,
+    types::{AccountConfig, ContextRuleParams, FeeConfig, Gift, SignerSpec},
+};
+
+/// Signer kind of passkeys, verified by the WASM in `Config::verifier`.
+const WEBAUTHN: Symbol = symbol_short!("webauthn");
+/// Signer kind of `SignerSpec::Ed25519` keys.
+const ED25519: Symbol = symbol_short!("ed25519");
+
+/// Salt domains of key-derived accounts and shared verifiers. Both deploy
+/// from the factory, so their salts hash different tags to keep anyone from
+/// choosing key data that lands an account on a verifier's address.
+const ACCOUNT_SALT: Symbol = symbol_short!("account");
+const VERIFIER_SALT: Symbol = symbol_short!("verifier");
 
-const ACCOUNT_HASH: &[u8; 32] = b"\xb9\x4b\x29\x9f\x8c\x53\x04\xf1\x63\xdf\x15\x2e\x0d\xcf\x1a\xb8\x06\x63\xed\x03\x7c\xa1\xa3\x85\xd4\xec\x7b\xee\x7f\x3e\xcc\xf3";
-const VERIFIER: &[u8; 32] = b"\xb9\x39\x33\x11\xf9\x7b\x49\x8f\xbb\x89\x76\xec\x50\xdd\x85\x85\xdd\x99\xad\x44\x3b\x8f\x13\xec\x5f\x75\x19\x86\x72\x9f\x99\xbe";
+/// Fees in basis points are charged out of this many parts of `amount`.
+const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Most accounts `create_accounts` deploys in one call, set by the mainnet
+/// limit of 50 ledger writes per transaction. A new account whose passkey
+/// has a credential ID writes 15 entries: its instance, five for its default
+/// context rule, its funding-token balance, the factory's record of it, and
+/// six registry entries (a length, slot and position for the public key and
+/// again for the credential ID). The batch also writes the funder's balance
+/// and index list once, so three accounts take 47 writes and a fourth would
+/// exceed the limit. The registry accounts for 6 of the 15 writes; without
+/// credential IDs it is 3 of 12. Writes run out before instructions do.
+/// The integration tests measure the writes and instructions one more account
+/// adds to a batch and check that this is the largest size that fits.
+const MAX_BATCH_SIZE: u32 = 3;
+
+/// The part of the smart account interface the factory reads back.
+#[contractclient(name = "SmartAccountClient")]
+pub trait SmartAccountInterface {
+    fn get_context_rules(e: Env, context_rule_type: ContextRuleType) -> Vec<ContextRule>;
+}
 
 #[contractstorage]
 pub struct Config {
+    admin: InstanceItem<Address>,
+    // Current smart-account WASM hash deployed by `create_account`.
     account: InstanceItem<BytesN<32>>,
-    passkey: InstanceItem<Address>,
+    // Current WebAuthn verifier WASM hash.
+    verifier: InstanceItem<BytesN<32>>,
+    account_version: InstanceItem<u32>,
+    verifier_version: InstanceItem<u32>,
+    // Every account WASM hash ever set, keyed by version (starting at 1).
+    account_history: PersistentMap<u32, BytesN<32>>,
+    // Every verifier WASM hash ever set, keyed by version (starting at 1).
+    verifier_history: PersistentMap<u32, BytesN<32>>,
+    // Verifier WASM hashes for signer kinds other than `webauthn`, which
+    // uses `verifier`.
+    verifier_kinds: PersistentMap<Symbol, BytesN<32>>,
+    // Registered keys of `verifier_kinds`, in registration order.
+    kinds: InstanceItem<Vec<Symbol>>,
+    // Account indices each funder has deployed, in creation order.
+    indices: PersistentMap<Address, Vec<u32>>,
+    // Accounts this factory deployed, the only callers of the signer hooks.
+    accounts: PersistentMap<Address, bool>,
+    fee: InstanceItem<FeeConfig>,
+    // Receives fees; set together with the fee.
+    treasury: InstanceItem<Address>,
+    // Funders that never pay the fee, e.g. partner exchanges.
+    fee_exempt: PersistentMap<Address, bool>,
+    // Can pause the factory alongside the admin.
+    guardian: InstanceItem<Address>,
+    // While set, no new accounts are deployed.
+    paused: InstanceItem<bool>,
 }
 
 #[contract]
@@ -20,48 +84,890 @@ pub struct Contract;
 
 #[contractimpl]
 impl Contract {
-    pub fn __constructor(e: &Env) {
-        xlm::register(e, &e.current_contract_address());
+    /// Initialize the factory with an admin and the initial account and
+    /// verifier WASM hashes (both recorded as version 1).
+    ///
+    /// `funding_token` is the SEP-41 token that `amount`s and fees are paid
+    /// in. `None` uses the network's native XLM asset contract.
+    pub fn __constructor(
+        e: &Env,
+        admin: &Address,
+        account_wasm: &BytesN<32>,
+        verifier_wasm: &BytesN<32>,
+        funding_token: Option<Address>,
+    ) {
+        let config = Config::new(e);
+        config.admin.set(admin);
+        config.account.set(account_wasm);
+        config.account_version.set(&1);
+        config.account_history.set(&1, account_wasm);
+        config.verifier.set(verifier_wasm);
+        config.verifier_version.set(&1);
+        config.verifier_history.set(&1, verifier_wasm);
+        funding::register(e, funding_token);
     }
 
     ///Deploy an account contract and add a passkey to it. Lastly transfer funds to the contract's account.
     ///
-    pub fn create_account(e: &Env, funder: &Address, key: BytesN<65>, amount: &i128) -> Address {
+    /// If the account already exists this only tops it up, provided `key` is a
+    /// signer on its default context rule.
+    ///
+    /// `index` selects which of the funder's accounts to create, so one
+    /// G-address can own several C-addresses. `None` is index 0, which keeps
+    /// the address the factory has always derived for a funder.
+    ///
+    /// `credential_id` is the WebAuthn credential ID of the passkey. It is
+    /// stored after the public key in the signer's key data so clients can
+    /// build `allowCredentials` from on-chain state.
+    pub fn create_account(
+        e: &Env,
+        funder: &Address,
+        key: BytesN<65>,
+        credential_id: Option<Bytes>,
+        amount: &i128,
+        index: Option<u32>,
+    ) -> Address {
+        Self::require_not_paused(e);
+        funder.require_auth();
+        let key_data = Self::key_data(&key, credential_id);
+        let index = index.unwrap_or_default();
+        let (account, deployed) = Self::funder_account(e, funder, key_data, index, *amount);
+        let token = funding::token_client(e);
+        token.transfer(funder, &account, amount);
+        if deployed {
+            Self::charge_fee(e, funder, *amount);
+        }
+        account
+    }
+
+    /// Same as `create_account`, but funds the account with any number of
+    /// SEP-41 tokens (e.g. XLM, USDC, EURC SACs) in a single transaction.
+    /// Each `(token, amount)` pair is transferred from `funder` in order.
+    pub fn create_account_with_assets(
+        e: &Env,
+        funder: &Address,
+        key: BytesN<65>,
+        credential_id: Option<Bytes>,
+        assets: Vec<(Address, i128)>,
+        index: Option<u32>,
+    ) -> Address {
+        Self::require_not_paused(e);
+        funder.require_auth();
+        let key_data = Self::key_data(&key, credential_id);
+        let funded = Self::funding_amount(e, &assets);
+        let index = index.unwrap_or_default();
+        let (account, deployed) = Self::funder_account(e, funder, key_data, index, funded);
+        for (token, amount) in assets {
+            TokenClient::new(e, &token).transfer(funder, &account, &amount);
+        }
+        if deployed {
+            Self::charge_fee(e, funder, funded);
+        }
+        account
+    }
+
+    /// Deploy an account with several initial signers, policies and extra
+    /// context rules in one call, then fund it with `amount` of the funding
+    /// token. Signers are given as `SignerSpec`s (passkey, Ed25519 key,
+    /// delegated address or another registered verifier kind); `External`
+    /// specs must name a kind registered with `register_verifier`. Unlike
+    /// `create_account`, this fails if the account at `index` already exists,
+    /// since an existing configuration cannot be reconciled.
+    pub fn create_account_with_config(
+        e: &Env,
+        funder: &Address,
+        config: AccountConfig,
+        amount: &i128,
+        index: Option<u32>,
+    ) -> Address {
+        Self::require_not_paused(e);
         funder.require_auth();
-        let new_account = Self::deploy_account_contract(e, funder, key.to_bytes());
-        let xlm_sac = xlm::stellar_asset_client(e);
-        xlm_sac.transfer(funder, &new_account, amount);
-        new_account
+        if config.signers.is_empty() {
+            panic_with_error!(e, FactoryError::NoSigners);
+        }
+        let index = index.unwrap_or_default();
+        let deployer = Self::deployer(e, funder, index);
+        if deployer.deployed_address().executable().is_some() {
+            panic_with_error!(e, FactoryError::AccountExists);
+        }
+        let account = Self::deploy_configured_account(e, &deployer, funder, &config, *amount);
+        Self::record_index(e, funder, index);
+        let token = funding::token_client(e);
+        token.transfer(funder, &account, amount);
+        Self::charge_fee(e, funder, *amount);
+        account
+    }
+
+    /// Deploy and fund several of the funder's accounts in one call. Each
+    /// entry is `(key, credential_id, amount, index)` and behaves like
+    /// `create_account` with those arguments, including topping up accounts
+    /// that already exist. At most `MAX_BATCH_SIZE` (3) entries are accepted.
+    pub fn create_accounts(
+        e: &Env,
+        funder: &Address,
+        entries: Vec<(BytesN<65>, Option<Bytes>, i128, u32)>,
+    ) -> Vec<Address> {
+        Self::require_not_paused(e);
+        funder.require_auth();
+        if entries.len() > MAX_BATCH_SIZE {
+            panic_with_error!(e, FactoryError::BatchTooLarge);
+        }
+        let token = funding::token_client(e);
+        let mut accounts = Vec::new(e);
+        for (key, credential_id, amount, index) in entries {
+            let key_data = Self::key_data(&key, credential_id);
+            let (account, deployed) = Self::funder_account(e, funder, key_data, index, amount);
+            token.transfer(funder, &account, &amount);
+            if deployed {
+                Self::charge_fee(e, funder, amount);
+            }
+            accounts.push_back(account);
+        }
+        accounts
+    }
+
+    pub fn get_c_address(e: &Env, funder: &Address, index: Option<u32>) -> Address {
+        Self::deployer(e, funder, index.unwrap_or_default()).deployed_address()
+    }
+
+    /// Predict the C-address of the account owned by `key`. The address only
+    /// depends on the factory, the passkey and its credential ID, so it can
+    /// be shared and funded before the user has a G-address or the account
+    /// exists.
+    pub fn predict_address(e: &Env, key: BytesN<65>, credential_id: Option<Bytes>) -> Address {
+        Self::key_deployer(e, &Self::key_data(&key, credential_id)).deployed_address()
+    }
+
+    /// Deploy the account at `predict_address(key, credential_id)` if it does
+    /// not exist yet, then transfer `amount` from `funder` into it. Any funder
+    /// can do this, since the address commits to the signer it is created
+    /// with.
+    pub fn create_account_for_key(
+        e: &Env,
+        key: BytesN<65>,
+        credential_id: Option<Bytes>,
+        funder: &Address,
+        amount: &i128,
+    ) -> Address {
+        Self::require_not_paused(e);
+        funder.require_auth();
+        Self::fund_key_account(e, &key, credential_id, funder, *amount)
+    }
+
+    /// Sponsored variant of `create_account_for_key`. `relayer` submits the
+    /// transaction and pays its fees; `funder` only signs an authorization
+    /// bound to `(key, credential_id, amount)`, so the relayer cannot
+    /// redirect the funds or change how much is moved.
+    pub fn create_account_sponsored(
+        e: &Env,
+        relayer: &Address,
+        funder: &Address,
+        key: BytesN<65>,
+        credential_id: Option<Bytes>,
+        amount: &i128,
+    ) -> Address {
+        Self::require_not_paused(e);
+        relayer.require_auth();
+        funder.require_auth_for_args((key.clone(), credential_id.clone(), *amount).into_val(e));
+        Self::fund_key_account(e, &key, credential_id, funder, *amount)
+    }
+
+    /// Hold `amount` of `token` in escrow until it is claimed with a
+    /// signature from the Ed25519 `claim_key`. The gift can be claimed up to
+    /// and including `expiry_ledger`; after that only `refund_gift` can
+    /// release it. The sender also escrows the account fee, in the funding
+    /// token, since the claim may deploy an account.
+    pub fn deposit_gift(
+        e: &Env,
+        sender: &Address,
+        token: &Address,
+        amount: &i128,
+        claim_key: &BytesN<32>,
+        expiry_ledger: u32,
+    ) {
+        sender.require_auth();
+        if *amount <= 0 {
+            panic_with_error!(e, FactoryError::InvalidAmount);
+        }
+        let funded = Self::funding_amount(e, &soroban_sdk::vec![e, (token.clone(), *amount)]);
+        let gift = Gift {
+            sender: sender.clone(),
+            token: token.clone(),
+            amount: *amount,
+            fee: Self::fee_due(e, sender, funded),
+            expiry_ledger,
+        };
+        gift::open(e, claim_key, &gift);
+        let escrow = e.current_contract_address();
+        TokenClient::new(e, token).transfer(sender, &escrow, amount);
+        if gift.fee > 0 {
+            funding::token_client(e).transfer(sender, &escrow, &gift.fee);
+        }
+        GiftDeposited {
+            sender: sender.clone(),
+            claim_key: claim_key.clone(),
+            token: token.clone(),
+            amount: *amount,
+            expiry_ledger,
+        }
+        .publish(e);
+    }
+
+    /// Deploy the account at `predict_address(key, credential_id)` if needed
+    /// and move the gift held under `claim_key` into it. No auth is
+    /// required: `signature` is the claim key's signature over
+    /// `gift_claim_payload(claim_key, key, credential_id)`, which binds the
+    /// gift to that account (see the `gift` module docs). The escrowed fee
+    /// goes to the treasury if this deploys the account, else to the sender.
+    pub fn claim_gift(
+        e: &Env,
+        claim_key: BytesN<32>,
+        key: BytesN<65>,
+        credential_id: Option<Bytes>,
+        signature: BytesN<64>,
+    ) -> Address {
+        Self::require_not_paused(e);
+        let gift = gift::claim(e, &claim_key, &key, &credential_id, &signature);
+        let funded =
+            Self::funding_amount(e, &soroban_sdk::vec![e, (gift.token.clone(), gift.amount)]);
+        let (account, deployed) = Self::key_account(e, &key, credential_id, &gift.sender, funded);
+        let escrow = e.current_contract_address();
+        TokenClient::new(e, &gift.token).transfer(&escrow, &account, &gift.amount);
+        if gift.fee > 0 {
+            let payee = if deployed {
+                Self::get_treasury(e).expect("treasury to be set with the fee")
+            } else {
+                gift.sender.clone()
+            };
+            funding::token_client(e).transfer(&escrow, &payee, &gift.fee);
+        }
+        GiftClaimed {
+            claim_key,
+            account: account.clone(),
+            token: gift.token,
+            amount: gift.amount,
+        }
+        .publish(e);
+        account
+    }
+
+    /// The message `claim_key` must sign for `claim_gift` to send its gift
+    /// to the account of `key` and `credential_id`.
+    pub fn gift_claim_payload(
+        e: &Env,
+        claim_key: BytesN<32>,
+        key: BytesN<65>,
+        credential_id: Option<Bytes>,
+    ) -> Bytes {
+        gift::claim_payload(e, &claim_key, &key, &credential_id)
+    }
+
+    /// Return an expired, unclaimed gift and its escrowed fee to the sender.
+    /// Anyone may call this, since the funds can only go back to the sender.
+    pub fn refund_gift(e: &Env, claim_key: &BytesN<32>) {
+        let gift = gift::refund(e, claim_key);
+        let escrow = e.current_contract_address();
+        TokenClient::new(e, &gift.token).transfer(&escrow, &gift.sender, &gift.amount);
+        if gift.fee > 0 {
+            funding::token_client(e).transfer(&escrow, &gift.sender, &gift.fee);
+        }
+        GiftRefunded {
+            claim_key: claim_key.clone(),
+            sender: gift.sender,
+            token: gift.token,
+            amount: gift.amount,
+        }
+        .publish(e);
+    }
+
+    /// The open gift escrow under `claim_key`, if any.
+    pub fn get_gift(e: &Env, claim_key: &BytesN<32>) -> Option<Gift> {
+        gift::get(e, claim_key)
+    }
+
+    /// Indices the funder has already used with `create_account`.
+    pub fn get_account_indices(e: &Env, funder: &Address) -> Vec<u32> {
+        Config::new(e)
+            .indices
+            .get(funder)
+            .unwrap_or_else(|| Vec::new(e))
+    }
+
+    /// Accounts that were created with, or have registered, this passkey
+    /// public key as a signer. Returns up to `limit` (at most 50) accounts
+    /// from position `start`; a shorter page means there are no more.
+    pub fn lookup_by_key(e: &Env, key: BytesN<65>, start: u32, limit: u32) -> Vec<Address> {
+        registry::lookup_by_key(e, &key, start, limit)
+    }
+
+    /// Accounts that were created with, or have registered, a signer with
+    /// this WebAuthn credential ID. Paginated like `lookup_by_key`.
+    pub fn lookup_by_credential(
+        e: &Env,
+        credential_id: Bytes,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Address> {
+        registry::lookup_by_credential(e, &credential_id, start, limit)
+    }
+
+    /// Hook for an account to index a signer it added. `key_data` is the
+    /// `Signer::External` key data: a 65-byte public key optionally followed
+    /// by the credential ID. Only accounts this factory deployed may call it,
+    /// and `key_data` must be a signer on one of their default context rules.
+    pub fn register_signer(e: &Env, account: &Address, key_data: Bytes) {
+        account.require_auth();
+        Self::require_deployed(e, account);
+        if !Self::has_default_key_data(e, account, &key_data) {
+            panic_with_error!(e, FactoryError::SignerNotFound);
+        }
+        registry::record(e, account, &key_data);
+    }
+
+    /// Hook for an account to drop a signer it removed from the index. Fails
+    /// while `key_data` is still a signer on a default context rule.
+    pub fn unregister_signer(e: &Env, account: &Address, key_data: Bytes) {
+        account.require_auth();
+        Self::require_deployed(e, account);
+        if Self::has_default_key_data(e, account, &key_data) {
+            panic_with_error!(e, FactoryError::SignerStillActive);
+        }
+        registry::remove(e, account, &key_data);
+    }
+
+    /// Replace the smart-account WASM used for new deployments. Accounts
+    /// already deployed keep their code. Returns the new version number.
+    pub fn set_account_wasm(e: &Env, wasm_hash: &BytesN<32>) -> u32 {
+        Self::require_admin(e);
+        let config = Config::new(e);
+        let version = config.account_version.get().unwrap_or_default() + 1;
+        config.account.set(wasm_hash);
+        config.account_version.set(&version);
+        config.account_history.set(&version, wasm_hash);
+        version
+    }
+
+    /// Replace the WebAuthn verifier WASM and deploy the new verifier. It
+    /// lives at a new address; accounts deployed earlier keep pointing at
+    /// the old one. Returns the new version number.
+    pub fn set_verifier_wasm(e: &Env, wasm_hash: &BytesN<32>) -> u32 {
+        Self::require_admin(e);
+        let config = Config::new(e);
+        let version = config.verifier_version.get().unwrap_or_default() + 1;
+        config.verifier.set(wasm_hash);
+        config.verifier_version.set(&version);
+        config.verifier_history.set(&version, wasm_hash);
+        Self::verifier_address(e, &WEBAUTHN);
+        version
+    }
+
+    /// Set the fee charged for each account the factory deploys and the
+    /// treasury it is paid to. The fee is paid in the funding token by the
+    /// funder on top of `amount`; top-ups of existing accounts are free.
+    /// `FeeConfig::Disabled` turns it off.
+    pub fn set_fee(e: &Env, fee: &FeeConfig, treasury: &Address) {
+        Self::require_admin(e);
+        let valid = match fee {
+            FeeConfig::Disabled => true,
+            FeeConfig::Flat(fee) => *fee >= 0,
+            FeeConfig::Bps(bps) => i128::from(*bps) <= BPS_DENOMINATOR,
+        };
+        if !valid {
+            panic_with_error!(e, FactoryError::InvalidFee);
+        }
+        let config = Config::new(e);
+        config.fee.set(fee);
+        config.treasury.set(treasury);
+    }
+
+    /// Exempt `funder` from the fee, or make it pay again.
+    pub fn set_fee_exempt(e: &Env, funder: &Address, exempt: bool) {
+        Self::require_admin(e);
+        let fee_exempt = Config::new(e).fee_exempt;
+        if exempt {
+            fee_exempt.set(funder, &true);
+        } else {
+            fee_exempt.remove(funder);
+        }
+    }
+
+    /// Set or clear the guardian, a second address (e.g. an ops key or
+    /// monitoring service) that can pause the factory without the admin.
+    pub fn set_guardian(e: &Env, guardian: Option<Address>) {
+        Self::require_admin(e);
+        let config = Config::new(e);
+        match guardian {
+            Some(guardian) => config.guardian.set(&guardian),
+            None => config.guardian.remove(),
+        }
+    }
+
+    /// Stop all account creation, e.g. when the account WASM has a known
+    /// vulnerability. Every `create_account*` entrypoint (top-ups of existing
+    /// accounts included), `claim_gift` and `ensure_verifier` fail with
+    /// `Paused`; gift deposits and refunds and address lookups keep working.
+    /// `caller` must be the admin or the guardian.
+    pub fn pause(e: &Env, caller: &Address) {
+        caller.require_auth();
+        if *caller != Self::get_admin(e) && Some(caller.clone()) != Self::get_guardian(e) {
+            panic_with_error!(e, FactoryError::Unauthorized);
+        }
+        Config::new(e).paused.set(&true);
+    }
+
+    /// Resume account creation. Only the admin can unpause, so a guardian key can
+    /// stop the factory quickly but not lift a pause on its own.
+    pub fn unpause(e: &Env) {
+        Self::require_admin(e);
+        Config::new(e).paused.set(&false);
+    }
+
+    pub fn is_paused(e: &Env) -> bool {
+        Config::new(e).paused.get().unwrap_or_default()
+    }
+
+    pub fn get_guardian(e: &Env) -> Option<Address> {
+        Config::new(e).guardian.get()
+    }
+
+    pub fn get_fee(e: &Env) -> FeeConfig {
+        Config::new(e).fee.get().unwrap_or(FeeConfig::Disabled)
+    }
+
+    pub fn get_treasury(e: &Env) -> Option<Address> {
+        Config::new(e).treasury.get()
+    }
+
+    pub fn is_fee_exempt(e: &Env, funder: &Address) -> bool {
+        Config::new(e).fee_exempt.get(funder).unwrap_or_default()
+    }
+
+    /// The token accounts are funded with.
+    pub fn funding_token(e: &Env) -> Address {
+        funding::contract_id(e)
     }
 
-    pub fn get_c_address(e: &Env, funder: &Address) -> Address {
-        Self::deployer(e, funder).deployed_address()
+    pub fn get_admin(e: &Env) -> Address {
+        Config::new(e).admin.get().expect("admin to be set")
     }
 
-    fn deployer(e: &Env, funder: &Address) -> DeployerWithAddress {
+    pub fn get_account_wasm(e: &Env) -> BytesN<32> {
+        Config::new(e)
+            .account
+            .get()
+            .expect("account wasm to be set")
+    }
+
+    pub fn get_account_wasm_version(e: &Env) -> u32 {
+        Config::new(e).account_version.get().unwrap_or_default()
+    }
+
+    /// Look up the account WASM hash recorded for a past or current version.
+    pub fn get_account_wasm_at(e: &Env, version: u32) -> Option<BytesN<32>> {
+        Config::new(e).account_history.get(&version)
+    }
+
+    /// Whether accounts that name the factory as their upgrade authority may
+    /// install `wasm_hash`. Only the current account WASM is approved, so
+    /// accounts cannot move back to a version the admin has replaced.
+    pub fn is_approved_account_wasm(e: &Env, wasm_hash: &BytesN<32>) -> bool {
+        *wasm_hash == Self::get_account_wasm(e)
+    }
+
+    pub fn get_verifier_wasm(e: &Env) -> BytesN<32> {
+        Config::new(e)
+            .verifier
+            .get()
+            .expect("verifier wasm to be set")
+    }
+
+    pub fn get_verifier_wasm_version(e: &Env) -> u32 {
+        Config::new(e).verifier_version.get().unwrap_or_default()
+    }
+
+    /// Look up the verifier WASM hash recorded for a past or current version.
+    pub fn get_verifier_wasm_at(e: &Env, version: u32) -> Option<BytesN<32>> {
+        Config::new(e).verifier_history.get(&version)
+    }
+
+    /// Allow `SignerSpec`s of `kind` and verify them with a shared instance
+    /// of `wasm_hash`, which is deployed right away. Registering `webauthn`
+    /// is the same as `set_verifier_wasm`.
+    pub fn register_verifier(e: &Env, kind: &Symbol, wasm_hash: &BytesN<32>) {
+        if *kind == WEBAUTHN {
+            Self::set_verifier_wasm(e, wasm_hash);
+            return;
+        }
+        Self::require_admin(e);
+        let config = Config::new(e);
+        config.verifier_kinds.set(kind, wasm_hash);
+        let mut kinds = config.kinds.get().unwrap_or_else(|| Vec::new(e));
+        if !kinds.contains(kind) {
+            kinds.push_back(kind.clone());
+            config.kinds.set(&kinds);
+        }
+        Self::verifier_address(e, kind);
+    }
+
+    /// Stop accepting signers of `kind` for new accounts. Accounts already
+    /// using the verifier are unaffected. The built-in `webauthn` kind cannot
+    /// be removed.
+    pub fn remove_verifier(e: &Env, kind: &Symbol) {
+        Self::require_admin(e);
+        let config = Config::new(e);
+        config.verifier_kinds.remove(kind);
+        if let Some(mut kinds) = config.kinds.get() {
+            if let Some(i) = kinds.first_index_of(kind) {
+                kinds.remove(i);
+                config.kinds.set(&kinds);
+            }
+        }
+    }
+
+    /// Every signer kind new accounts can use, starting with `webauthn`.
+    pub fn get_verifier_kinds(e: &Env) -> Vec<Symbol> {
+        let mut kinds = soroban_sdk::vec![e, WEBAUTHN];
+        if let Some(registered) = Config::new(e).kinds.get() {
+            kinds.append(&registered);
+        }
+        kinds
+    }
+
+    /// Address of the shared verifier for `kind`, whether or not it has been
+    /// deployed yet. Clients can use it to build `Signer::External` values
+    /// without a transaction.
+    pub fn get_verifier_address(e: &Env, kind: &Symbol) -> Address {
+        Self::verifier_deployer(e, kind).deployed_address()
+    }
+
+    /// Whether the shared verifier for `kind` has been deployed.
+    pub fn is_verifier_deployed(e: &Env, kind: &Symbol) -> bool {
+        Self::get_verifier_address(e, kind).executable().is_some()
+    }
+
+    /// Deploy the shared verifier for `kind` if needed and return its address.
+    /// Verifiers are deployed when registered, except the WebAuthn verifier
+    /// given to the constructor, which deploys with the first account.
+    pub fn ensure_verifier(e: &Env, kind: &Symbol) -> Address {
+        Self::require_not_paused(e);
+        Self::verifier_address(e, kind)
+    }
+
+    /// The verifier WASM hash registered for `kind`, if any.
+    pub fn get_verifier_kind_wasm(e: &Env, kind: &Symbol) -> Option<BytesN<32>> {
+        if *kind == WEBAUTHN {
+            Some(Self::get_verifier_wasm(e))
+        } else {
+            Config::new(e).verifier_kinds.get(kind)
+        }
+    }
+
+    fn require_admin(e: &Env) {
+        Self::get_admin(e).require_auth();
+    }
+
+    fn require_not_paused(e: &Env) {
+        if Self::is_paused(e) {
+            panic_with_error!(e, FactoryError::Paused);
+        }
+    }
+
+    /// Transfer the configured fee for one newly deployed account funded
+    /// with `amount` from `funder` to the treasury, unless the funder is
+    /// exempt. Top-ups of existing accounts are not charged.
+    fn charge_fee(e: &Env, funder: &Address, amount: i128) {
+        let fee = Self::fee_due(e, funder, amount);
+        if fee > 0 {
+            let treasury = Self::get_treasury(e).expect("treasury to be set with the fee");
+            funding::token_client(e).transfer(funder, &treasury, &fee);
+        }
+    }
+
+    /// The fee `funder` owes for one newly deployed account funded with
+    /// `amount`; zero when fees are disabled or the funder is exempt.
+    fn fee_due(e: &Env, funder: &Address, amount: i128) -> i128 {
+        if Self::is_fee_exempt(e, funder) {
+            return 0;
+        }
+        match Self::get_fee(e) {
+            FeeConfig::Disabled => 0,
+            FeeConfig::Flat(fee) => fee,
+            FeeConfig::Bps(bps) => amount * i128::from(bps) / BPS_DENOMINATOR,
+        }
+    }
+
+    fn deployer(e: &Env, funder: &Address, index: u32) -> DeployerWithAddress {
         e.deployer()
-            .with_address(funder.clone(), BytesN::from_array(e, &[0; 32]))
+            .with_address(funder.clone(), Self::salt(e, index))
     }
 
-    fn deploy_account_contract(e: &Env, funder: &Address, key: Bytes) -> Address {
-        let verifier_addr = Self::verifier_address(e);
-        let signer = Signer::External(verifier_addr, key);
-        let signers = soroban_sdk::vec![e, signer];
-        let policies: soroban_sdk::Map<soroban_sdk::Address, soroban_sdk::Val> =
-            soroban_sdk::Map::new(e);
-        Self::deployer(e, funder)
-            .deploy_v2(BytesN::from_array(e, ACCOUNT_HASH), (&signers, &policies))
+    /// Deploy the account for `key` if needed and move `amount` of the
+    /// funding token into it.
+    fn fund_key_account(
+        e: &Env,
+        key: &BytesN<65>,
+        credential_id: Option<Bytes>,
+        funder: &Address,
+        amount: i128,
+    ) -> Address {
+        let (account, deployed) = Self::key_account(e, key, credential_id, funder, amount);
+        let token = funding::token_client(e);
+        token.transfer(funder, &account, &amount);
+        if deployed {
+            Self::charge_fee(e, funder, amount);
+        }
+        account
     }
 
-    fn verifier_address(e: &Env) -> Address {
-        let bytes: BytesN<32> = BytesN::from_array(e, VERIFIER);
-        let deployer = e.deployer().with_current_contract(bytes.clone());
+    /// The account at `predict_address(key, credential_id)`, deployed on
+    /// first use, and whether this call deployed it. `amount` is only
+    /// reported in the `AccountCreated` event.
+    fn key_account(
+        e: &Env,
+        key: &BytesN<65>,
+        credential_id: Option<Bytes>,
+        funder: &Address,
+        amount: i128,
+    ) -> (Address, bool) {
+        let key_data = Self::key_data(key, credential_id);
+        let deployer = Self::key_deployer(e, &key_data);
+        let account = deployer.deployed_address();
+        if account.executable().is_some() {
+            return (account, false);
+        }
+        let account = Self::deploy_account_contract(e, &deployer, funder, key_data, amount);
+        (account, true)
+    }
+
+    /// Deploys from the factory itself, salted with
+    /// `sha256(("account", key_data))`. Committing to the credential ID as
+    /// well as the public key means nobody can take a user's address first
+    /// with a wrong credential ID.
+    fn key_deployer(e: &Env, key_data: &Bytes) -> DeployerWithAddress {
+        let salt = e
+            .crypto()
+            .sha256(&(ACCOUNT_SALT, key_data.clone()).to_xdr(e));
+        e.deployer().with_current_contract(salt.to_bytes())
+    }
+
+    /// Index 0 maps to the all-zero salt used before indices existed.
+    fn salt(e: &Env, index: u32) -> BytesN<32> {
+        let mut salt = [0; 32];
+        salt[28..].copy_from_slice(&index.to_be_bytes());
+        BytesN::from_array(e, &salt)
+    }
+
+    /// Deploy the funder's account at `index`, or check that `key` may top up
+    /// the one already there. Also returns whether this call deployed it.
+    fn funder_account(
+        e: &Env,
+        funder: &Address,
+        key_data: Bytes,
+        index: u32,
+        amount: i128,
+    ) -> (Address, bool) {
+        let deployer = Self::deployer(e, funder, index);
+        let account = deployer.deployed_address();
+        if account.executable().is_none() {
+            let account = Self::deploy_account_contract(e, &deployer, funder, key_data, amount);
+            Self::record_index(e, funder, index);
+            (account, true)
+        } else if Self::has_default_signer(e, &account, &key_data) {
+            (account, false)
+        } else {
+            panic_with_error!(e, FactoryError::SignerMismatch)
+        }
+    }
+
+    fn record_index(e: &Env, funder: &Address, index: u32) {
+        let indices = Config::new(e).indices;
+        let mut used = indices.get(funder).unwrap_or_else(|| Vec::new(e));
+        used.push_back(index);
+        indices.set(funder, &used);
+    }
+
+    /// Total of the funding token in a list of `(token, amount)` pairs.
+    fn funding_amount(e: &Env, assets: &Vec<(Address, i128)>) -> i128 {
+        let funding_token = funding::contract_id(e);
+        assets
+            .iter()
+            .filter(|(token, _)| *token == funding_token)
+            .map(|(_, amount)| amount)
+            .sum()
+    }
+
+    /// Deploy a smart account with `key` as its only passkey signer.
+    fn deploy_account_contract(
+        e: &Env,
+        deployer: &DeployerWithAddress,
+        funder: &Address,
+        key: Bytes,
+        amount: i128,
+    ) -> Address {
+        let signer = SignerSpec::External(WEBAUTHN, key);
+        let config = AccountConfig {
+            signers: soroban_sdk::vec![e, signer],
+            policies: Map::new(e),
+            context_rules: Vec::new(e),
+        };
+        Self::deploy_configured_account(e, deployer, funder, &config, amount)
+    }
+
+    /// Deploy a smart account from a full configuration, index its passkey
+    /// signers and publish `AccountCreated`. `config.signers` must not be
+    /// empty.
+    fn deploy_configured_account(
+        e: &Env,
+        deployer: &DeployerWithAddress,
+        funder: &Address,
+        config: &AccountConfig,
+        amount: i128,
+    ) -> Address {
+        let signers = Self::resolve_signers(e, &config.signers);
+        let context_rules = Vec::from_iter(
+            e,
+            config.context_rules.iter().map(|rule| ContextRuleParams {
+                context_type: rule.context_type,
+                name: rule.name,
+                valid_until: rule.valid_until,
+                signers: Self::resolve_signers(e, &rule.signers),
+                policies: rule.policies,
+            }),
+        );
+        let account_wasm = Self::get_account_wasm(e);
+        let account = deployer.deploy_v2(
+            account_wasm.clone(),
+            (&signers, &config.policies, &context_rules),
+        );
+        Config::new(e).accounts.set(&account, &true);
+        for (spec, signer) in config.signers.iter().zip(signers.iter()) {
+            if !Self::is_passkey(&spec) {
+                continue;
+            }
+            if let Signer::External(_, key_data) = signer {
+                registry::record(e, &account, &key_data);
+            }
+        }
+        AccountCreated {
+            funder: funder.clone(),
+            account: account.clone(),
+            signer: signers.first_unchecked(),
+            amount,
+            account_wasm,
+        }
+        .publish(e);
+        account
+    }
+
+    /// `External` signer key data: the 65-byte public key, followed by the
+    /// WebAuthn credential ID when one is given.
+    fn key_data(key: &BytesN<65>, credential_id: Option<Bytes>) -> Bytes {
+        let mut key_data = key.to_bytes();
+        if let Some(credential_id) = credential_id {
+            key_data.append(&credential_id);
+        }
+        key_data
+    }
+
+    /// Whether the public key in `key_data` belongs to an `External` signer on
+    /// one of the account's default context rules. Credential IDs are not
+    /// compared.
+    fn has_default_signer(e: &Env, account: &Address, key_data: &Bytes) -> bool {
+        let key = key_data.slice(0..65);
+        Self::default_key_data(e, account)
+            .iter()
+            .any(|key_data| key_data.len() >= 65 && key_data.slice(0..65) == key)
+    }
+
+    /// Whether `key_data` is exactly the key data of an `External` signer on
+    /// one of the account's default context rules.
+    fn has_default_key_data(e: &Env, account: &Address, key_data: &Bytes) -> bool {
+        Self::default_key_data(e, account).contains(key_data)
+    }
+
+    /// Key data of every `External` signer on the account's default context
+    /// rules.
+    fn default_key_data(e: &Env, account: &Address) -> Vec<Bytes> {
+        let rules =
+            SmartAccountClient::new(e, account).get_context_rules(&ContextRuleType::Default);
+        Vec::from_iter(
+            e,
+            rules
+                .iter()
+                .flat_map(|rule| rule.signers)
+                .filter_map(|signer| match signer {
+                    Signer::External(_, key_data) => Some(key_data),
+                    Signer::Delegated(_) => None,
+                }),
+        )
+    }
+
+    fn require_deployed(e: &Env, account: &Address) {
+        if !Config::new(e).accounts.get(account).unwrap_or_default() {
+            panic_with_error!(e, FactoryError::UnknownAccount);
+        }
+    }
+
+    fn resolve_signers(e: &Env, specs: &Vec<SignerSpec>) -> Vec<Signer> {
+        Vec::from_iter(e, specs.iter().map(|spec| Self::resolve_signer(e, spec)))
+    }
+
+    fn resolve_signer(e: &Env, spec: SignerSpec) -> Signer {
+        match spec {
+            SignerSpec::WebAuthn(key, credential_id) => Signer::External(
+                Self::verifier_address(e, &WEBAUTHN),
+                Self::key_data(&key, credential_id),
+            ),
+            SignerSpec::Ed25519(key) => {
+                Signer::External(Self::verifier_address(e, &ED25519), key.to_bytes())
+            }
+            SignerSpec::Delegated(address) => Signer::Delegated(address),
+            SignerSpec::External(kind, key_data) => {
+                Signer::External(Self::verifier_address(e, &kind), key_data)
+            }
+        }
+    }
+
+    /// Whether the spec is a passkey, whose key data the registry indexes.
+    fn is_passkey(spec: &SignerSpec) -> bool {
+        match spec {
+            SignerSpec::WebAuthn(..) => true,
+            SignerSpec::External(kind, _) => *kind == WEBAUTHN,
+            SignerSpec::Ed25519(_) | SignerSpec::Delegated(_) => false,
+        }
+    }
+
+    /// WASM hash of the verifier registered for `kind`.
+    fn verifier_wasm(e: &Env, kind: &Symbol) -> BytesN<32> {
+        if *kind == WEBAUTHN {
+            return Self::get_verifier_wasm(e);
+        }
+        Config::new(e)
+            .verifier_kinds
+            .get(kind)
+            .unwrap_or_else(|| panic_with_error!(e, FactoryError::UnknownVerifier))
+    }
+
+    /// Address of the shared verifier for `kind`, deployed on first use.
+    fn verifier_address(e: &Env, kind: &Symbol) -> Address {
+        let deployer = Self::verifier_deployer(e, kind);
         let address = deployer.deployed_address();
 
         if address.executable().is_none() {
-            deployer.deploy_v2(bytes, ())
+            deployer.deploy_v2(Self::verifier_wasm(e, kind), ())
         } else {
             address
         }
     }
+
+    /// Verifiers deploy from the factory, salted with
+    /// `sha256(("verifier", kind, wasm))`, so two kinds sharing a WASM still
+    /// get their own verifier.
+    fn verifier_deployer(e: &Env, kind: &Symbol) -> DeployerWithAddress {
+        let wasm = Self::verifier_wasm(e, kind);
+        let salt = e
+            .crypto()
+            .sha256(&(VERIFIER_SALT, kind.clone(), wasm).to_xdr(e));
+        e.deployer().with_current_contract(salt.to_bytes())
+    }
 }
@@ -0,0 +1,43 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FactoryError {
+    /// An account already exists at the requested address, but the supplied
+    /// key is not a signer on its default context rule.
+    SignerMismatch = 1,
+    /// An account already exists at the requested address and the entrypoint
+    /// cannot top it up.
+    AccountExists = 2,
+    /// An account configuration has no signers on its default rule.
+    NoSigners = 3,
+    /// A gift escrow is already open under this claim key.
+    GiftExists = 4,
+    /// No gift escrow is open under this claim key.
+    GiftNotFound = 5,
+    /// The gift's expiry ledger has passed, or would already have passed
+    /// when depositing.
+    GiftExpired = 6,
+    /// The gift can still be claimed, so it cannot be refunded yet.
+    GiftNotExpired = 7,
+    /// A batch has more entries than one call may deploy.
+    BatchTooLarge = 8,
+    /// A flat fee is negative or a basis-point fee exceeds 10 000.
+    InvalidFee = 9,
+    /// The caller is neither the admin nor the guardian.
+    Unauthorized = 10,
+    /// The factory is paused and does not create or fund accounts.
+    Paused = 11,
+    /// A signer spec names a verifier kind the factory has no WASM for.
+    UnknownVerifier = 12,
+    /// The signer hooks were called by an account this factory did not
+    /// deploy.
+    UnknownAccount = 13,
+    /// The key data to index is not a signer on the account's default rules.
+    SignerNotFound = 14,
+    /// The key data to drop from the index is still a signer on the account.
+    SignerStillActive = 15,
+    /// A gift amount is zero or negative.
+    InvalidAmount = 16,
+}
@@ -0,0 +1,56 @@
+use soroban_sdk::{contractevent, Address, BytesN};
+use stellar_accounts::smart_account::Signer;
+
+/// Published whenever the factory deploys a new smart account, whichever
+/// entrypoint deployed it. Top-ups of existing accounts do not emit it.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountCreated {
+    #[topic]
+    pub funder: Address,
+    #[topic]
+    pub account: Address,
+    /// First signer of the account's default rule: a passkey or other
+    /// `External` signer with its verifier, or a `Delegated` address.
+    pub signer: Signer,
+    /// Amount of the factory's funding token moved into the account.
+    pub amount: i128,
+    pub account_wasm: BytesN<32>,
+}
+
+/// Published when a sender opens a gift escrow.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GiftDeposited {
+    #[topic]
+    pub sender: Address,
+    #[topic]
+    pub claim_key: BytesN<32>,
+    pub token: Address,
+    pub amount: i128,
+    pub expiry_ledger: u32,
+}
+
+/// Published when a gift is claimed into a passkey account.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GiftClaimed {
+    #[topic]
+    pub claim_key: BytesN<32>,
+    #[topic]
+    pub account: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when an expired gift is returned to its sender.
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GiftRefunded {
+    #[topic]
+    pub claim_key: BytesN<32>,
+    #[topic]
+    pub sender: Address,
+    pub token: Address,
+    pub amount: i128,
+}
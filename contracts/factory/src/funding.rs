@@ -0,0 +1,44 @@
+//! The token the factory funds new accounts with.
+//!
+//! It is set once at construction. Without an explicit address the factory
+//! uses the native XLM Stellar Asset Contract of whatever network it is on,
+//! deploying it first if needed (e.g. on a fresh local network).
+
+use soroban_sdk::{token::TokenClient, Address, Env};
+use soroban_sdk_tools::{contractstorage, InstanceItem};
+
+/// XDR of `Asset::Native`, from which the native SAC address is derived.
+const NATIVE_ASSET: [u8; 4] = [0, 0, 0, 0];
+
+#[contractstorage]
+pub struct Funding {
+    funding_token: InstanceItem<Address>,
+}
+
+/// Store the funding token, falling back to the native SAC.
+pub fn register(e: &Env, token: Option<Address>) {
+    let token = token.unwrap_or_else(|| native_asset(e));
+    Funding::new(e).funding_token.set(&token);
+}
+
+pub fn contract_id(e: &Env) -> Address {
+    Funding::new(e)
+        .funding_token
+        .get()
+        .expect("funding token to be set")
+}
+
+pub fn token_client<'a>(e: &Env) -> TokenClient<'a> {
+    TokenClient::new(e, &contract_id(e))
+}
+
+/// Address of the native SAC, deployed on first use.
+fn native_asset(e: &Env) -> Address {
+    let deployer = e.deployer().with_stellar_asset(NATIVE_ASSET);
+    let address = deployer.deployed_address();
+    if address.executable().is_none() {
+        deployer.deploy()
+    } else {
+        address
+    }
+}
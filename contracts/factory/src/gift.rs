@@ -0,0 +1,90 @@
+//! Escrow for funding a passkey account before it exists.
+//!
+//! A sender generates an Ed25519 claim keypair, deposits tokens under its
+//! public key and shares the private key with the recipient out of band. To
+//! claim, the recipient signs `claim_payload` for the passkey that should
+//! own the account. The signature commits to that passkey and credential ID,
+//! so a front-runner who sees a pending claim can only replay it into the
+//! same account, never redirect the gift. The private key stays a bearer
+//! secret until the claim lands.
+
+use soroban_sdk::{panic_with_error, xdr::ToXdr, Bytes, BytesN, Env};
+use soroban_sdk_tools::{contractstorage, PersistentMap};
+
+use crate::{error::FactoryError, types::Gift};
+
+#[contractstorage]
+pub struct Gifts {
+    // Open escrows, keyed by their Ed25519 claim public key.
+    escrows: PersistentMap<BytesN<32>, Gift>,
+}
+
+pub fn get(e: &Env, claim_key: &BytesN<32>) -> Option<Gift> {
+    Gifts::new(e).escrows.get(claim_key)
+}
+
+/// The message the claim key signs to send its gift to the account of `key`
+/// and `credential_id`. It names this factory, so a signature cannot be
+/// replayed against another deployment.
+pub fn claim_payload(
+    e: &Env,
+    claim_key: &BytesN<32>,
+    key: &BytesN<65>,
+    credential_id: &Option<Bytes>,
+) -> Bytes {
+    (
+        e.current_contract_address(),
+        claim_key.clone(),
+        key.clone(),
+        credential_id.clone(),
+    )
+        .to_xdr(e)
+}
+
+/// Open an escrow. Claim keys cannot be reused while an escrow is open.
+pub fn open(e: &Env, claim_key: &BytesN<32>, gift: &Gift) {
+    let escrows = Gifts::new(e).escrows;
+    if escrows.get(claim_key).is_some() {
+        panic_with_error!(e, FactoryError::GiftExists);
+    }
+    if gift.expiry_ledger <= e.ledger().sequence() {
+        panic_with_error!(e, FactoryError::GiftExpired);
+    }
+    escrows.set(claim_key, gift);
+}
+
+/// Close an unexpired escrow for the account of `key` and `credential_id`,
+/// which `signature` must authorize under the claim key.
+pub fn claim(
+    e: &Env,
+    claim_key: &BytesN<32>,
+    key: &BytesN<65>,
+    credential_id: &Option<Bytes>,
+    signature: &BytesN<64>,
+) -> Gift {
+    let gift = take(e, claim_key);
+    let payload = claim_payload(e, claim_key, key, credential_id);
+    e.crypto().ed25519_verify(claim_key, &payload, signature);
+    if e.ledger().sequence() > gift.expiry_ledger {
+        panic_with_error!(e, FactoryError::GiftExpired);
+    }
+    gift
+}
+
+/// Close an expired escrow so it can be returned to its sender.
+pub fn refund(e: &Env, claim_key: &BytesN<32>) -> Gift {
+    let gift = take(e, claim_key);
+    if e.ledger().sequence() <= gift.expiry_ledger {
+        panic_with_error!(e, FactoryError::GiftNotExpired);
+    }
+    gift
+}
+
+fn take(e: &Env, claim_key: &BytesN<32>) -> Gift {
+    let escrows = Gifts::new(e).escrows;
+    let Some(gift) = escrows.get(claim_key) else {
+        panic_with_error!(e, FactoryError::GiftNotFound)
+    };
+    escrows.remove(claim_key);
+    gift
+}
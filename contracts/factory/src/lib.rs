@@ -2,4 +2,9 @@
 #![allow(dead_code)]
 
 mod contract;
-pub(crate) mod xlm;
+mod error;
+mod events;
+pub(crate) mod funding;
+mod gift;
+mod registry;
+mod types;
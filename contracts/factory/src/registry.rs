@@ -0,0 +1,179 @@
+//! Discovery index from passkeys to the accounts they control.
+//!
+//! Entries are keyed by `sha256` of the 65-byte public key and, when the
+//! signer key data carries one, `sha256` of the WebAuthn credential ID that
+//! follows it. Anyone can deploy an account with any public key, so wallets
+//! should treat lookups as candidates and confirm control with an assertion.
+//!
+//! Each account under an entry is stored in its own slot rather than in one
+//! growing list, so spamming an entry with accounts cannot make it too large
+//! to read or update. Lookups are paginated; removal moves the last slot into
+//! the freed one, so order is not stable across removals.
+
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Vec};
+use soroban_sdk_tools::{contractstorage, PersistentMap};
+
+const PUBLIC_KEY_LEN: u32 = 65;
+
+/// Most accounts one lookup returns.
+const MAX_PAGE_SIZE: u32 = 50;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum Entry {
+    Key(BytesN<32>),
+    Credential(BytesN<32>),
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Slot {
+    pub entry: Entry,
+    pub position: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Member {
+    pub entry: Entry,
+    pub account: Address,
+}
+
+#[contractstorage]
+pub struct Registry {
+    // Number of accounts under each entry.
+    lengths: PersistentMap<Entry, u32>,
+    // Account in each occupied slot of an entry.
+    slots: PersistentMap<Slot, Address>,
+    // Slot position of each account under an entry.
+    positions: PersistentMap<Member, u32>,
+}
+
+/// Record `account` under the public key and credential ID in `key_data`.
+pub fn record(e: &Env, account: &Address, key_data: &Bytes) {
+    for entry in entries(e, key_data) {
+        insert(e, entry, account);
+    }
+}
+
+/// Drop `account` from the entries for the public key and credential ID in
+/// `key_data`.
+pub fn remove(e: &Env, account: &Address, key_data: &Bytes) {
+    for entry in entries(e, key_data) {
+        delete(e, entry, account);
+    }
+}
+
+/// Up to `limit` accounts indexed under `key`, starting at `start`.
+pub fn lookup_by_key(e: &Env, key: &BytesN<65>, start: u32, limit: u32) -> Vec<Address> {
+    let hash = e.crypto().sha256(&key.to_bytes()).to_bytes();
+    page(e, Entry::Key(hash), start, limit)
+}
+
+/// Up to `limit` accounts indexed under `credential_id`, starting at `start`.
+pub fn lookup_by_credential(
+    e: &Env,
+    credential_id: &Bytes,
+    start: u32,
+    limit: u32,
+) -> Vec<Address> {
+    let hash = e.crypto().sha256(credential_id).to_bytes();
+    page(e, Entry::Credential(hash), start, limit)
+}
+
+fn insert(e: &Env, entry: Entry, account: &Address) {
+    let registry = Registry::new(e);
+    let member = Member {
+        entry: entry.clone(),
+        account: account.clone(),
+    };
+    if registry.positions.get(&member).is_some() {
+        return;
+    }
+    let position = registry.lengths.get(&entry).unwrap_or(0);
+    registry.slots.set(
+        &Slot {
+            entry: entry.clone(),
+            position,
+        },
+        account,
+    );
+    registry.positions.set(&member, &position);
+    registry.lengths.set(&entry, &(position + 1));
+}
+
+fn delete(e: &Env, entry: Entry, account: &Address) {
+    let registry = Registry::new(e);
+    let member = Member {
+        entry: entry.clone(),
+        account: account.clone(),
+    };
+    let Some(position) = registry.positions.get(&member) else {
+        return;
+    };
+    let last = registry
+        .lengths
+        .get(&entry)
+        .expect("entry to have a length")
+        - 1;
+    let last_slot = Slot {
+        entry: entry.clone(),
+        position: last,
+    };
+    if position != last {
+        let moved = registry.slots.get(&last_slot).expect("slot to be occupied");
+        registry.slots.set(
+            &Slot {
+                entry: entry.clone(),
+                position,
+            },
+            &moved,
+        );
+        registry.positions.set(
+            &Member {
+                entry: entry.clone(),
+                account: moved,
+            },
+            &position,
+        );
+    }
+    registry.slots.remove(&last_slot);
+    registry.positions.remove(&member);
+    if last == 0 {
+        registry.lengths.remove(&entry);
+    } else {
+        registry.lengths.set(&entry, &last);
+    }
+}
+
+fn page(e: &Env, entry: Entry, start: u32, limit: u32) -> Vec<Address> {
+    let registry = Registry::new(e);
+    let len = registry.lengths.get(&entry).unwrap_or(0);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(len);
+    let mut accounts = Vec::new(e);
+    for position in start..end {
+        let slot = Slot {
+            entry: entry.clone(),
+            position,
+        };
+        accounts.push_back(registry.slots.get(&slot).expect("slot to be occupied"));
+    }
+    accounts
+}
+
+/// The index entries `key_data` belongs under: its public key and, if
+/// present, its credential ID.
+fn entries(e: &Env, key_data: &Bytes) -> Vec<Entry> {
+    let mut entries = Vec::new(e);
+    if key_data.len() >= PUBLIC_KEY_LEN {
+        let key = key_data.slice(..PUBLIC_KEY_LEN);
+        entries.push_back(Entry::Key(e.crypto().sha256(&key).to_bytes()));
+    }
+    if key_data.len() > PUBLIC_KEY_LEN {
+        let credential_id = key_data.slice(PUBLIC_KEY_LEN..);
+        entries.push_back(Entry::Credential(
+            e.crypto().sha256(&credential_id).to_bytes(),
+        ));
+    }
+    entries
+}
@@ -0,0 +1,80 @@
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Map, String, Symbol, Val, Vec};
+use stellar_accounts::smart_account::{ContextRuleType, Signer};
+
+/// An additional context rule for a new account. Mirrors the smart account's
+/// `ContextRuleParams` constructor argument.
+#[contracttype]
+#[derive(Clone)]
+pub struct ContextRuleParams {
+    pub context_type: ContextRuleType,
+    pub name: String,
+    pub valid_until: Option<u32>,
+    pub signers: Vec<Signer>,
+    pub policies: Map<Address, Val>,
+}
+
+/// A signer to install on a new account. The factory resolves each spec to a
+/// `Signer`, pointing `External` signers at the verifier it manages for the
+/// spec's kind, so callers cannot bind accounts to arbitrary verifiers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignerSpec {
+    /// A passkey: uncompressed P-256 public key and optional credential ID.
+    WebAuthn(BytesN<65>, Option<Bytes>),
+    /// A raw Ed25519 public key, checked by the `ed25519` verifier.
+    Ed25519(BytesN<32>),
+    /// An existing Stellar account or contract that authorizes natively.
+    Delegated(Address),
+    /// Key data for any other registered verifier kind (e.g. `secp256k1`).
+    External(Symbol, Bytes),
+}
+
+/// `ContextRuleParams` with signer specs instead of resolved signers.
+#[contracttype]
+#[derive(Clone)]
+pub struct ContextRuleSpec {
+    pub context_type: ContextRuleType,
+    pub name: String,
+    pub valid_until: Option<u32>,
+    pub signers: Vec<SignerSpec>,
+    pub policies: Map<Address, Val>,
+}
+
+/// Full initial configuration of a smart account: the default rule's signers
+/// and policies (policy address to install param), plus any extra rules.
+#[contracttype]
+#[derive(Clone)]
+pub struct AccountConfig {
+    pub signers: Vec<SignerSpec>,
+    pub policies: Map<Address, Val>,
+    pub context_rules: Vec<ContextRuleSpec>,
+}
+
+/// Fee the factory charges funders for each account it deploys, in its
+/// funding token. Topping up an existing account is free.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeConfig {
+    Disabled,
+    /// A fixed amount per deployed account.
+    Flat(i128),
+    /// Basis points of the new account's funded amount (100 = 1%).
+    Bps(u32),
+}
+
+/// Tokens held by the factory until the holder of the claim key creates
+/// their account, or until `expiry_ledger` passes and the sender takes them
+/// back.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Gift {
+    pub sender: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// Account fee paid by the sender in the funding token at deposit. It
+    /// goes to the treasury if the claim deploys the account and back to the
+    /// sender otherwise.
+    pub fee: i128,
+    /// Last ledger at which the gift can be claimed.
+    pub expiry_ledger: u32,
+}
@@ -4,17 +4,88 @@
 
 use soroban_sdk::{
     auth::{Context, CustomAccountInterface},
-    contract, contractimpl,
+    contract, contractclient, contractimpl, contracttype,
     crypto::Hash,
-    Address, Env, Map, String, Symbol, Val, Vec,
+    panic_with_error, symbol_short,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env, InvokeError, Map, String, Symbol, TryFromVal, Val, Vec,
 };
+use soroban_sdk_tools::{contractstorage, InstanceItem};
 use stellar_accounts::smart_account::{
     add_context_rule, add_policy, add_signer, do_check_auth, get_context_rule, get_context_rules,
     get_context_rules_count, remove_context_rule, remove_policy, remove_signer,
     update_context_rule_name, update_context_rule_valid_until, ContextRule, ContextRuleType,
-    ExecutionEntryPoint, Signatures, Signer, SmartAccount, SmartAccountError,
+    Signatures, Signer, SmartAccount, SmartAccountError,
 };
 
+use crate::{
+    error::G2CAccountError,
+    events::{
+        OperationCancelled, OperationExecuted, OperationScheduled, RecoveryApproved,
+        RecoveryCancelled, RecoveryExecuted, RecoveryInitiated, SessionGranted, SessionRevoked,
+    },
+    recovery::{self, ActiveRecovery, RecoveryConfig},
+    session,
+    timelock::{self, AdminOp, ScheduledOperation},
+};
+
+/// Length of the uncompressed secp256r1 public key at the start of passkey
+/// signer key data.
+const PUBLIC_KEY_LEN: u32 = 65;
+
+/// `Policy` entrypoints that set up or tear down a policy's state for one of
+/// the account's rules.
+const POLICY_INSTALL: Symbol = symbol_short!("install");
+const POLICY_UNINSTALL: Symbol = symbol_short!("uninstall");
+
+/// Version of the account's storage layout. Bump it together with a new step
+/// in `migrate` whenever an upgrade changes what is stored.
+const SCHEMA_VERSION: u32 = 1;
+
+/// Contract that decides which account WASM hashes may be installed, usually
+/// the G2C factory.
+#[contractclient(name = "UpgradeAuthorityClient")]
+pub trait UpgradeAuthority {
+    fn is_approved_account_wasm(e: Env, wasm_hash: BytesN<32>) -> bool;
+}
+
+/// The interface of `Signer::External` verifiers, used to check cancellations.
+#[contractclient(name = "SignatureVerifierClient")]
+pub trait SignatureVerifier {
+    fn verify(e: Env, signature_payload: Bytes, key_data: Bytes, sig_data: Bytes) -> bool;
+}
+
+#[contractstorage]
+pub struct Config {
+    // When set, `upgrade` only accepts hashes this contract approves.
+    upgrade_authority: InstanceItem<Address>,
+    // Storage layout version; missing on accounts deployed before versioning.
+    schema_version: InstanceItem<u32>,
+}
+
+/// An additional context rule to install at construction, with the same
+/// fields as `SmartAccount::add_context_rule`.
+#[contracttype]
+#[derive(Clone)]
+pub struct ContextRuleParams {
+    pub context_type: ContextRuleType,
+    pub name: String,
+    pub valid_until: Option<u32>,
+    pub signers: Vec<Signer>,
+    pub policies: Map<Address, Val>,
+}
+
+/// Outcome of a `try_invoke` call that did not abort.
+#[contracttype]
+#[derive(Clone)]
+pub enum CallResult {
+    /// The target returned this value.
+    Success(Val),
+    /// The target failed with this contract error code; its changes were
+    /// rolled back.
+    ContractError(u32),
+}
+
 #[contract]
 pub struct G2CSmartAccount;
 
@@ -29,8 +100,14 @@ impl G2CSmartAccount {
     ///
     /// * `signers` - Initial signers (e.g., passkey via `WebAuthn` verifier)
     /// * `policies` - Optional policies (e.g., spending limits)
+    /// * `context_rules` - Extra context rules installed after the default one
     #[allow(clippy::needless_pass_by_value)]
-    pub fn __constructor(e: &Env, signers: Vec<Signer>, policies: Map<Address, Val>) {
+    pub fn __constructor(
+        e: &Env,
+        signers: Vec<Signer>,
+        policies: Map<Address, Val>,
+        context_rules: Vec<ContextRuleParams>,
+    ) {
         add_context_rule(
             e,
             &ContextRuleType::Default,
@@ -39,6 +116,470 @@ impl G2CSmartAccount {
             &signers,
             &policies,
         );
+        for rule in context_rules {
+            add_context_rule(
+                e,
+                &rule.context_type,
+                &rule.name,
+                rule.valid_until,
+                &rule.signers,
+                &rule.policies,
+            );
+        }
+        Config::new(e).schema_version.set(&SCHEMA_VERSION);
+    }
+
+    /// Replace the account's code with `new_wasm_hash`, keeping its address,
+    /// storage and balances. Requires the account's own auth and, if an
+    /// upgrade authority is set, its approval of the hash.
+    ///
+    /// The new code takes effect after this call returns. A transaction runs
+    /// a single invocation and the account cannot call itself, so `migrate`
+    /// has to be called in a follow-up transaction to bring storage up to
+    /// date.
+    pub fn upgrade(e: &Env, new_wasm_hash: BytesN<32>) {
+        e.current_contract_address().require_auth();
+        timelock::require_no_delay(e);
+        Self::install_wasm(e, new_wasm_hash);
+    }
+
+    /// Run the storage migrations between the stored schema version and the
+    /// one this code expects. Does nothing when storage is already current.
+    pub fn migrate(e: &Env) {
+        e.current_contract_address().require_auth();
+        let config = Config::new(e);
+        let stored = config.schema_version.get().unwrap_or_default();
+        if stored > SCHEMA_VERSION {
+            panic_with_error!(e, G2CAccountError::SchemaTooNew);
+        }
+        // Version 1 only introduced the version itself; later layout changes
+        // add their steps here, e.g. `if stored < 2 { ... }`.
+        config.schema_version.set(&SCHEMA_VERSION);
+    }
+
+    pub fn get_schema_version(e: &Env) -> u32 {
+        Config::new(e).schema_version.get().unwrap_or_default()
+    }
+
+    /// Restrict `upgrade` to hashes approved by `authority` (typically the
+    /// factory's `is_approved_account_wasm`), or lift the restriction.
+    pub fn set_upgrade_authority(e: &Env, authority: Option<Address>) {
+        e.current_contract_address().require_auth();
+        timelock::require_no_delay(e);
+        Self::store_upgrade_authority(e, authority);
+    }
+
+    pub fn get_upgrade_authority(e: &Env) -> Option<Address> {
+        Config::new(e).upgrade_authority.get()
+    }
+
+    /// Require sensitive operations (signer, policy and rule changes,
+    /// upgrades) to be scheduled `ledgers` ahead. Once a delay is set,
+    /// changing it is itself a scheduled operation.
+    pub fn set_admin_delay(e: &Env, ledgers: u32) {
+        e.current_contract_address().require_auth();
+        timelock::require_no_delay(e);
+        timelock::set_delay(e, ledgers);
+    }
+
+    pub fn get_admin_delay(e: &Env) -> u32 {
+        timelock::delay(e)
+    }
+
+    /// Schedule `operation` to become executable after the admin delay.
+    /// Returns the operation id.
+    pub fn schedule(e: &Env, operation: AdminOp) -> u32 {
+        e.current_contract_address().require_auth();
+        let scheduled = timelock::schedule(e, operation);
+        OperationScheduled {
+            id: scheduled.id,
+            operation: scheduled.operation,
+            ready_ledger: scheduled.ready_ledger,
+        }
+        .publish(e);
+        scheduled.id
+    }
+
+    /// Apply a scheduled operation whose delay has passed. Anyone may call
+    /// this: the account authorized the operation when scheduling it.
+    pub fn execute_scheduled(e: &Env, id: u32) {
+        let scheduled = timelock::take_ready(e, id);
+        Self::apply(e, scheduled.operation);
+        OperationExecuted { id }.publish(e);
+    }
+
+    /// Cancel a scheduled operation before it is executed. `signer` must be on
+    /// the default context rule. A `Delegated` signer authorizes this call
+    /// directly; an `External` signer passes `sig_data` for its verifier over
+    /// `cancel_payload(id)`.
+    ///
+    /// This deliberately bypasses the account's own auth, so the owner can
+    /// cancel with any one of their keys even if another key is compromised.
+    pub fn cancel_scheduled(e: &Env, id: u32, signer: &Signer, sig_data: &Bytes) {
+        if timelock::get(e, id).is_none() {
+            panic_with_error!(e, G2CAccountError::OperationNotFound);
+        }
+        Self::require_default_signer(e, signer);
+        Self::require_signer_proof(e, signer, &Self::cancel_payload(e, id), sig_data);
+        timelock::take(e, id);
+        OperationCancelled { id }.publish(e);
+    }
+
+    /// The payload an `External` signer signs to cancel operation `id`.
+    pub fn cancel_payload(e: &Env, id: u32) -> BytesN<32> {
+        let message = (symbol_short!("cancel"), e.current_contract_address(), id);
+        e.crypto().sha256(&message.to_xdr(e)).to_bytes()
+    }
+
+    /// Name guardians who can recover the account, or remove them. Drops any
+    /// recoveries in progress.
+    pub fn set_recovery(e: &Env, config: Option<RecoveryConfig>) {
+        e.current_contract_address().require_auth();
+        timelock::require_no_delay(e);
+        recovery::set_config(e, config);
+    }
+
+    pub fn get_recovery(e: &Env) -> Option<RecoveryConfig> {
+        recovery::config(e)
+    }
+
+    /// The open recovery to `new_signers`, if any.
+    pub fn get_active_recovery(e: &Env, new_signers: Vec<Signer>) -> Option<ActiveRecovery> {
+        recovery::active(e, &Self::recovery_payload(e, new_signers))
+    }
+
+    /// Start replacing the default rule's signers with `new_signers`. The
+    /// initiating guardian's approval counts towards the threshold; see
+    /// `cancel_scheduled` for how `guardian` and `sig_data` are checked, with
+    /// `recovery_payload(new_signers)` as the signed payload. Recoveries to
+    /// different signers can be open at the same time.
+    pub fn initiate_recovery(
+        e: &Env,
+        guardian: &Signer,
+        new_signers: Vec<Signer>,
+        sig_data: &Bytes,
+    ) {
+        let config = recovery::require_guardian(e, guardian);
+        let payload = Self::recovery_payload(e, new_signers.clone());
+        Self::require_signer_proof(e, guardian, &payload, sig_data);
+        recovery::start(e, &payload, new_signers.clone());
+        let active = recovery::approve(e, &config, &payload, guardian.clone());
+        RecoveryInitiated {
+            nonce: recovery::nonce(e),
+            guardian: guardian.clone(),
+            new_signers,
+            ready_ledger: active.ready_ledger,
+        }
+        .publish(e);
+    }
+
+    /// Add a guardian's approval to the open recovery to `new_signers`.
+    pub fn approve_recovery(
+        e: &Env,
+        guardian: &Signer,
+        new_signers: Vec<Signer>,
+        sig_data: &Bytes,
+    ) {
+        let config = recovery::require_guardian(e, guardian);
+        let payload = Self::recovery_payload(e, new_signers.clone());
+        recovery::require_active(e, &payload);
+        Self::require_signer_proof(e, guardian, &payload, sig_data);
+        let active = recovery::approve(e, &config, &payload, guardian.clone());
+        RecoveryApproved {
+            nonce: recovery::nonce(e),
+            guardian: guardian.clone(),
+            new_signers,
+            ready_ledger: active.ready_ledger,
+        }
+        .publish(e);
+    }
+
+    /// Replace the default rule's signers with `new_signers` once that
+    /// recovery has enough approvals and its delay has passed. Anyone may
+    /// call this. New signers are added before old ones are removed, so the
+    /// rule never ends up empty. Other open recoveries are dropped.
+    ///
+    /// The factory's discovery index is not updated, so the replaced
+    /// passkeys still list this account until the new owner calls the
+    /// factory's `unregister_signer` through `execute`.
+    pub fn execute_recovery(e: &Env, new_signers: Vec<Signer>) {
+        let active = recovery::require_ready(e, &Self::recovery_payload(e, new_signers));
+        let rule = get_context_rules(e, &ContextRuleType::Default).first_unchecked();
+        for signer in active.new_signers.iter() {
+            if !rule.signers.contains(&signer) {
+                add_signer(e, rule.id, &signer);
+            }
+        }
+        for signer in rule.signers.iter() {
+            if !active.new_signers.contains(&signer) {
+                remove_signer(e, rule.id, &signer);
+            }
+        }
+        RecoveryExecuted {
+            nonce: recovery::nonce(e),
+            new_signers: active.new_signers,
+        }
+        .publish(e);
+        recovery::end(e);
+    }
+
+    /// Veto every open recovery. Requires the account's own auth, i.e. a
+    /// current signer, and is never delayed.
+    pub fn cancel_recovery(e: &Env) {
+        e.current_contract_address().require_auth();
+        RecoveryCancelled {
+            nonce: recovery::nonce(e),
+        }
+        .publish(e);
+        recovery::end(e);
+    }
+
+    /// The payload guardians sign to recover the account to `new_signers`.
+    /// It changes after every finished or cancelled recovery.
+    pub fn recovery_payload(e: &Env, new_signers: Vec<Signer>) -> BytesN<32> {
+        let message = (
+            symbol_short!("recover"),
+            e.current_contract_address(),
+            recovery::nonce(e),
+            new_signers,
+        );
+        e.crypto().sha256(&message.to_xdr(e)).to_bytes()
+    }
+
+    /// Call `target_fn` on `target` as the account and return its result.
+    /// A failing call aborts the transaction.
+    pub fn execute(e: &Env, target: Address, target_fn: Symbol, target_args: Vec<Val>) -> Val {
+        e.current_contract_address().require_auth();
+        Self::require_not_policy_setup(e, &target_fn, &target_args);
+        e.invoke_contract::<Val>(&target, &target_fn, target_args)
+    }
+
+    /// Like `execute`, but a target that fails with a contract error comes
+    /// back as `CallResult::ContractError(code)` instead of an abort, so its
+    /// codes cannot be mistaken for the account's own errors. The target's
+    /// changes are rolled back. Any other failure (a panic, a missing
+    /// function) is `G2CAccountError::CallFailed`.
+    ///
+    /// Not named `try_execute`, which the generated client already uses for
+    /// the fallible form of `execute`.
+    pub fn try_invoke(
+        e: &Env,
+        target: Address,
+        target_fn: Symbol,
+        target_args: Vec<Val>,
+    ) -> Result<CallResult, G2CAccountError> {
+        e.current_contract_address().require_auth();
+        Self::require_not_policy_setup(e, &target_fn, &target_args);
+        match e.try_invoke_contract::<Val, InvokeError>(&target, &target_fn, target_args) {
+            Ok(Ok(value)) => Ok(CallResult::Success(value)),
+            Err(Ok(InvokeError::Contract(code))) => Ok(CallResult::ContractError(code)),
+            _ => Err(G2CAccountError::CallFailed),
+        }
+    }
+
+    /// Run several contract calls under a single authorization of the
+    /// account, e.g. an approve followed by a swap, and return their results
+    /// in order. If any call fails the whole batch reverts.
+    pub fn execute_batch(e: &Env, calls: Vec<(Address, Symbol, Vec<Val>)>) -> Vec<Val> {
+        e.current_contract_address().require_auth();
+        let mut results = Vec::new(e);
+        for (target, target_fn, target_args) in calls {
+            Self::require_not_policy_setup(e, &target_fn, &target_args);
+            results.push_back(e.invoke_contract::<Val>(&target, &target_fn, target_args));
+        }
+        results
+    }
+
+    /// Set the verifier that checks session keys, typically the shared
+    /// Ed25519 verifier. Existing sessions keep the verifier they were
+    /// granted with. The verifier decides which keys can sign sessions, so
+    /// this is subject to the admin delay.
+    pub fn set_session_verifier(e: &Env, verifier: Address) {
+        e.current_contract_address().require_auth();
+        timelock::require_no_delay(e);
+        session::set_verifier(e, &verifier);
+    }
+
+    pub fn get_session_verifier(e: &Env) -> Option<Address> {
+        session::verifier(e)
+    }
+
+    /// Let `key` sign calls to `contract`, and nothing else, until ledger
+    /// `valid_until`. `limits` are policies (e.g. spending limits) installed
+    /// on the session's context rule. Returns the rule's id.
+    ///
+    /// `contract` cannot be the account itself, and `valid_until` must lie
+    /// in the future but at most `MAX_SESSION_LEDGERS` ledgers away. Within
+    /// those bounds sessions are not subject to the admin delay: they cannot
+    /// change the account's signers and expire on their own.
+    pub fn grant_session(
+        e: &Env,
+        contract: Address,
+        key: BytesN<32>,
+        valid_until: u32,
+        limits: Map<Address, Val>,
+    ) -> u32 {
+        e.current_contract_address().require_auth();
+        session::validate(e, &contract, valid_until);
+        let verifier = session::require_verifier(e);
+        let signers = Vec::from_array(e, [Signer::External(verifier, key.to_bytes())]);
+        let rule = add_context_rule(
+            e,
+            &ContextRuleType::CallContract(contract.clone()),
+            &String::from_str(e, "session"),
+            Some(valid_until),
+            &signers,
+            &limits,
+        );
+        session::record(e, rule.id);
+        SessionGranted {
+            context_rule_id: rule.id,
+            contract,
+            key,
+            valid_until,
+        }
+        .publish(e);
+        rule.id
+    }
+
+    /// Remove a session granted by `grant_session` before it expires.
+    pub fn revoke_session(e: &Env, context_rule_id: u32) {
+        e.current_contract_address().require_auth();
+        session::take(e, context_rule_id);
+        remove_context_rule(e, context_rule_id);
+        SessionRevoked { context_rule_id }.publish(e);
+    }
+
+    pub fn get_scheduled_operation(e: &Env, id: u32) -> Option<ScheduledOperation> {
+        timelock::get(e, id)
+    }
+
+    /// Operations scheduled and neither executed nor cancelled yet.
+    pub fn get_pending_operations(e: &Env) -> Vec<ScheduledOperation> {
+        timelock::pending(e)
+    }
+
+    /// Return the `WebAuthn` credential IDs of the passkey signers on a context
+    /// rule, for use as `allowCredentials`.
+    ///
+    /// Passkey key data is the 65-byte public key followed by the credential
+    /// ID; signers without a credential ID suffix are skipped.
+    pub fn get_credential_ids(e: &Env, context_rule_id: u32) -> Vec<Bytes> {
+        let mut credential_ids = Vec::new(e);
+        for signer in get_context_rule(e, context_rule_id).signers {
+            if let Signer::External(_, key_data) = signer {
+                if key_data.len() > PUBLIC_KEY_LEN {
+                    credential_ids.push_back(key_data.slice(PUBLIC_KEY_LEN..));
+                }
+            }
+        }
+        credential_ids
+    }
+
+    fn install_wasm(e: &Env, new_wasm_hash: BytesN<32>) {
+        if let Some(authority) = Config::new(e).upgrade_authority.get() {
+            let authority = UpgradeAuthorityClient::new(e, &authority);
+            if !authority.is_approved_account_wasm(&new_wasm_hash) {
+                panic_with_error!(e, G2CAccountError::UnapprovedWasm);
+            }
+        }
+        e.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    fn store_upgrade_authority(e: &Env, authority: Option<Address>) {
+        let config = Config::new(e);
+        match authority {
+            Some(authority) => config.upgrade_authority.set(&authority),
+            None => config.upgrade_authority.remove(),
+        }
+    }
+
+    fn apply(e: &Env, operation: AdminOp) {
+        match operation {
+            AdminOp::AddSigner(rule_id, signer) => {
+                add_signer(e, rule_id, &signer);
+            }
+            AdminOp::RemoveSigner(rule_id, signer) => {
+                remove_signer(e, rule_id, &signer);
+            }
+            AdminOp::AddPolicy(rule_id, policy, install_param) => {
+                add_policy(e, rule_id, &policy, install_param);
+            }
+            AdminOp::RemovePolicy(rule_id, policy) => {
+                remove_policy(e, rule_id, &policy);
+            }
+            AdminOp::AddContextRule(rule) => {
+                add_context_rule(
+                    e,
+                    &rule.context_type,
+                    &rule.name,
+                    rule.valid_until,
+                    &rule.signers,
+                    &rule.policies,
+                );
+            }
+            AdminOp::RemoveContextRule(rule_id) => {
+                remove_context_rule(e, rule_id);
+            }
+            AdminOp::UpdateValidUntil(rule_id, valid_until) => {
+                update_context_rule_valid_until(e, rule_id, valid_until);
+            }
+            AdminOp::Upgrade(wasm_hash) => {
+                Self::install_wasm(e, wasm_hash);
+            }
+            AdminOp::SetUpgradeAuthority(authority) => {
+                Self::store_upgrade_authority(e, authority);
+            }
+            AdminOp::SetDelay(ledgers) => {
+                timelock::set_delay(e, ledgers);
+            }
+            AdminOp::SetRecovery(config) => {
+                recovery::set_config(e, config);
+            }
+            AdminOp::SetSessionVerifier(verifier) => {
+                session::set_verifier(e, &verifier);
+            }
+        }
+    }
+
+    fn require_default_signer(e: &Env, signer: &Signer) {
+        let is_signer = get_context_rules(e, &ContextRuleType::Default)
+            .iter()
+            .any(|rule| rule.signers.contains(signer));
+        if !is_signer {
+            panic_with_error!(e, G2CAccountError::NotASigner);
+        }
+    }
+
+    /// Policies are installed and uninstalled by the account calling them
+    /// directly from `add_policy`, `remove_policy` and the context rule
+    /// functions, all subject to the admin delay. Any other way of
+    /// authorizing a policy's `install` or `uninstall` for this account,
+    /// through the `execute` family or a signed auth entry, would let signers
+    /// reset or drop a policy without that delay, so it is refused.
+    fn require_not_policy_setup(e: &Env, target_fn: &Symbol, target_args: &Vec<Val>) {
+        let is_setup = *target_fn == POLICY_INSTALL || *target_fn == POLICY_UNINSTALL;
+        // The account is the last argument of both entrypoints.
+        let names_account = target_args.last().is_some_and(|arg| {
+            Address::try_from_val(e, &arg).is_ok_and(|arg| arg == e.current_contract_address())
+        });
+        if is_setup && names_account {
+            panic_with_error!(e, G2CAccountError::PolicySetupCall);
+        }
+    }
+
+    /// Check that `signer` approves `payload`: a `Delegated` signer through
+    /// its own auth, an `External` one through `sig_data` and its verifier.
+    fn require_signer_proof(e: &Env, signer: &Signer, payload: &BytesN<32>, sig_data: &Bytes) {
+        match signer {
+            Signer::Delegated(address) => address.require_auth(),
+            Signer::External(verifier, key_data) => {
+                let verifier = SignatureVerifierClient::new(e, verifier);
+                if !verifier.verify(&payload.to_bytes(), key_data, sig_data) {
+                    panic_with_error!(e, G2CAccountError::InvalidSignature);
+                }
+            }
+        }
     }
 }
 
@@ -53,6 +594,11 @@ impl CustomAccountInterface for G2CSmartAccount {
         signatures: Signatures,
         auth_contexts: Vec<Context>,
     ) -> Result<(), Self::Error> {
+        for context in auth_contexts.iter() {
+            if let Context::Contract(call) = context {
+                Self::require_not_policy_setup(&e, &call.fn_name, &call.args);
+            }
+        }
         do_check_auth(&e, &signature_payload, &signatures, &auth_contexts)
     }
 }
@@ -80,6 +626,7 @@ impl SmartAccount for G2CSmartAccount {
         policies: Map<Address, Val>,
     ) -> ContextRule {
         e.current_contract_address().require_auth();
+        timelock::require_no_delay(e);
         add_context_rule(e, &context_type, &name, valid_until, &signers, &policies)
     }
 
@@ -94,39 +641,37 @@ impl SmartAccount for G2CSmartAccount {
         valid_until: Option<u32>,
     ) -> ContextRule {
         e.current_contract_address().require_auth();
+        timelock::require_no_delay(e);
         update_context_rule_valid_until(e, context_rule_id, valid_until)
     }
 
     fn remove_context_rule(e: &Env, context_rule_id: u32) {
         e.current_contract_address().require_auth();
+        timelock::require_no_delay(e);
         remove_context_rule(e, context_rule_id);
     }
 
     fn add_signer(e: &Env, context_rule_id: u32, signer: Signer) {
         e.current_contract_address().require_auth();
+        timelock::require_no_delay(e);
         add_signer(e, context_rule_id, &signer);
     }
 
     fn remove_signer(e: &Env, context_rule_id: u32, signer: Signer) {
         e.current_contract_address().require_auth();
+        timelock::require_no_delay(e);
         remove_signer(e, context_rule_id, &signer);
     }
 
     fn add_policy(e: &Env, context_rule_id: u32, policy: Address, install_param: Val) {
         e.current_contract_address().require_auth();
+        timelock::require_no_delay(e);
         add_policy(e, context_rule_id, &policy, install_param);
     }
 
     fn remove_policy(e: &Env, context_rule_id: u32, policy: Address) {
         e.current_contract_address().require_auth();
+        timelock::require_no_delay(e);
         remove_policy(e, context_rule_id, &policy);
     }
 }
-
-#[contractimpl]
-impl ExecutionEntryPoint for G2CSmartAccount {
-    fn execute(e: &Env, target: Address, target_fn: Symbol, target_args: Vec<Val>) {
-        e.current_contract_address().require_auth();
-        e.invoke_contract::<Val>(&target, &target_fn, target_args);
-    }
-}
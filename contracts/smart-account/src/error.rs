@@ -0,0 +1,50 @@
+use soroban_sdk::contracterror;
+
+/// Errors from the G2C-specific account entrypoints. Errors from the
+/// OpenZeppelin smart account module keep their `SmartAccountError` codes.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum G2CAccountError {
+    /// The upgrade authority has not approved the requested WASM hash.
+    UnapprovedWasm = 1,
+    /// The stored schema version is newer than this code understands, e.g.
+    /// after upgrading to an older WASM.
+    SchemaTooNew = 2,
+    /// An admin delay is set, so the operation has to be scheduled.
+    DelayActive = 3,
+    /// No operation is scheduled under this id.
+    OperationNotFound = 4,
+    /// The operation's delay has not passed yet.
+    OperationNotReady = 5,
+    /// The signer is not on the account's default context rule.
+    NotASigner = 6,
+    /// The signer's signature over the cancellation payload is invalid.
+    InvalidSignature = 7,
+    /// The recovery threshold is zero or exceeds the number of guardians, the
+    /// delay is zero, a guardian is listed twice, or a recovery has no new
+    /// signers.
+    InvalidRecoveryConfig = 8,
+    /// The signer is not a configured guardian.
+    NotAGuardian = 9,
+    /// A recovery to the same signers is already in progress.
+    RecoveryInProgress = 10,
+    /// No recovery to these signers is in progress.
+    NoActiveRecovery = 11,
+    /// The recovery lacks approvals or its delay has not passed yet.
+    RecoveryNotReady = 12,
+    /// No session verifier is set, so sessions cannot be granted.
+    NoSessionVerifier = 13,
+    /// The context rule was not created by `grant_session`.
+    NotASession = 14,
+    /// A `try_invoke` target call failed without a contract error, e.g. it
+    /// panicked or the function does not exist.
+    CallFailed = 15,
+    /// A session targets the account itself, or its `valid_until` is not in
+    /// the future or more than `MAX_SESSION_LEDGERS` ledgers away.
+    InvalidSession = 16,
+    /// A policy's `install` or `uninstall` for this account was called other
+    /// than through `add_policy`, `remove_policy` or the context rule
+    /// functions.
+    PolicySetupCall = 17,
+}
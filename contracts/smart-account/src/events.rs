@@ -0,0 +1,90 @@
+use soroban_sdk::{contractevent, Address, BytesN, Vec};
+use stellar_accounts::smart_account::Signer;
+
+use crate::timelock::AdminOp;
+
+/// Published when an admin operation is scheduled behind the delay.
+#[contractevent]
+#[derive(Clone)]
+pub struct OperationScheduled {
+    #[topic]
+    pub id: u32,
+    pub operation: AdminOp,
+    pub ready_ledger: u32,
+}
+
+/// Published when a scheduled operation is applied.
+#[contractevent]
+#[derive(Clone)]
+pub struct OperationExecuted {
+    #[topic]
+    pub id: u32,
+}
+
+/// Published when a signer cancels a scheduled operation.
+#[contractevent]
+#[derive(Clone)]
+pub struct OperationCancelled {
+    #[topic]
+    pub id: u32,
+}
+
+/// Published when a guardian starts a recovery.
+#[contractevent]
+#[derive(Clone)]
+pub struct RecoveryInitiated {
+    #[topic]
+    pub nonce: u32,
+    pub guardian: Signer,
+    pub new_signers: Vec<Signer>,
+    pub ready_ledger: Option<u32>,
+}
+
+/// Published when a guardian approves an open recovery. `ready_ledger` is
+/// set once the threshold is reached.
+#[contractevent]
+#[derive(Clone)]
+pub struct RecoveryApproved {
+    #[topic]
+    pub nonce: u32,
+    pub guardian: Signer,
+    pub new_signers: Vec<Signer>,
+    pub ready_ledger: Option<u32>,
+}
+
+/// Published when a recovery replaces the default rule's signers.
+#[contractevent]
+#[derive(Clone)]
+pub struct RecoveryExecuted {
+    #[topic]
+    pub nonce: u32,
+    pub new_signers: Vec<Signer>,
+}
+
+/// Published when the owner vetoes the open recoveries.
+#[contractevent]
+#[derive(Clone)]
+pub struct RecoveryCancelled {
+    #[topic]
+    pub nonce: u32,
+}
+
+/// Published when a session key is granted for `contract`.
+#[contractevent]
+#[derive(Clone)]
+pub struct SessionGranted {
+    #[topic]
+    pub context_rule_id: u32,
+    #[topic]
+    pub contract: Address,
+    pub key: BytesN<32>,
+    pub valid_until: u32,
+}
+
+/// Published when a session is revoked before it expires.
+#[contractevent]
+#[derive(Clone)]
+pub struct SessionRevoked {
+    #[topic]
+    pub context_rule_id: u32,
+}
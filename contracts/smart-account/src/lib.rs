@@ -2,3 +2,8 @@
 #![allow(dead_code)]
 
 mod contract;
+mod error;
+mod events;
+mod recovery;
+mod session;
+mod timelock;
@@ -0,0 +1,163 @@
+//! Social recovery by guardians.
+//!
+//! The owner names guardians (any `Signer`: other accounts, G-addresses or
+//! passkeys) and a threshold. Once `threshold` guardians have approved the
+//! same set of new signers, the recovery can be executed after `delay`
+//! ledgers, replacing the default rule's signers. Until then the owner can
+//! veto it with the account's own auth.
+//!
+//! Recoveries are keyed by their payload, which commits to the new signers
+//! and the recovery nonce, so guardians can propose different signer sets
+//! side by side and one guardian cannot block the others by proposing
+//! signers nobody else approves. Executing or vetoing a recovery bumps the
+//! nonce, which retires every open proposal at once.
+//!
+//! The delay must be at least one ledger so the owner always has a chance to
+//! veto, and guardians must be distinct so the threshold stays reachable.
+//!
+//! Recovery does not touch the factory's discovery index: the replaced
+//! passkeys stay listed under the account until `unregister_signer` is
+//! called on the factory through the account.
+
+use soroban_sdk::{contracttype, panic_with_error, BytesN, Env, Vec};
+use soroban_sdk_tools::{contractstorage, InstanceItem, PersistentMap};
+use stellar_accounts::smart_account::Signer;
+
+use crate::error::G2CAccountError;
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RecoveryConfig {
+    pub guardians: Vec<Signer>,
+    /// Number of guardian approvals a recovery needs.
+    pub threshold: u32,
+    /// Ledgers between reaching the threshold and executing the recovery.
+    /// Must not be zero.
+    pub delay: u32,
+}
+
+/// A proposed recovery to one set of new signers.
+#[contracttype]
+#[derive(Clone)]
+pub struct ActiveRecovery {
+    /// Signers that will replace the default rule's signers.
+    pub new_signers: Vec<Signer>,
+    /// Guardians that have approved so far.
+    pub approvals: Vec<Signer>,
+    /// Set once the threshold is reached.
+    pub ready_ledger: Option<u32>,
+}
+
+#[contractstorage]
+pub struct Guardians {
+    recovery_config: InstanceItem<RecoveryConfig>,
+    // Open proposals, keyed by their recovery payload.
+    proposals: PersistentMap<BytesN<32>, ActiveRecovery>,
+    // Bumped whenever a recovery ends, so guardian signatures are single use.
+    recovery_nonce: InstanceItem<u32>,
+}
+
+pub fn config(e: &Env) -> Option<RecoveryConfig> {
+    Guardians::new(e).recovery_config.get()
+}
+
+/// Replace the recovery configuration, dropping any recoveries in progress.
+pub fn set_config(e: &Env, config: Option<RecoveryConfig>) {
+    let guardians = Guardians::new(e);
+    match config {
+        Some(config) => {
+            let guardian_count = config.guardians.len();
+            if config.threshold == 0
+                || config.threshold > guardian_count
+                || config.delay == 0
+                || has_duplicates(&config.guardians)
+            {
+                panic_with_error!(e, G2CAccountError::InvalidRecoveryConfig);
+            }
+            guardians.recovery_config.set(&config);
+        }
+        None => guardians.recovery_config.remove(),
+    }
+    end(e);
+}
+
+fn has_duplicates(guardians: &Vec<Signer>) -> bool {
+    (1..guardians.len()).any(|i| guardians.slice(..i).contains(guardians.get_unchecked(i)))
+}
+
+/// The open recovery with payload `id`, if any.
+pub fn active(e: &Env, id: &BytesN<32>) -> Option<ActiveRecovery> {
+    Guardians::new(e).proposals.get(id)
+}
+
+pub fn nonce(e: &Env) -> u32 {
+    Guardians::new(e).recovery_nonce.get().unwrap_or_default()
+}
+
+/// Fail unless `guardian` is one of the configured guardians.
+pub fn require_guardian(e: &Env, guardian: &Signer) -> RecoveryConfig {
+    match config(e) {
+        Some(config) if config.guardians.contains(guardian) => config,
+        _ => panic_with_error!(e, G2CAccountError::NotAGuardian),
+    }
+}
+
+/// Open a recovery to `new_signers` under its payload `id`.
+pub fn start(e: &Env, id: &BytesN<32>, new_signers: Vec<Signer>) {
+    let guardians = Guardians::new(e);
+    if guardians.proposals.get(id).is_some() {
+        panic_with_error!(e, G2CAccountError::RecoveryInProgress);
+    }
+    if new_signers.is_empty() {
+        panic_with_error!(e, G2CAccountError::InvalidRecoveryConfig);
+    }
+    guardians.proposals.set(
+        id,
+        &ActiveRecovery {
+            new_signers,
+            approvals: Vec::new(e),
+            ready_ledger: None,
+        },
+    );
+}
+
+/// Record `guardian`'s approval of the recovery `id`. Starts the delay when
+/// the threshold is reached.
+pub fn approve(
+    e: &Env,
+    config: &RecoveryConfig,
+    id: &BytesN<32>,
+    guardian: Signer,
+) -> ActiveRecovery {
+    let guardians = Guardians::new(e);
+    let mut recovery = require_active(e, id);
+    if !recovery.approvals.contains(&guardian) {
+        recovery.approvals.push_back(guardian);
+    }
+    if recovery.ready_ledger.is_none() && recovery.approvals.len() >= config.threshold {
+        recovery.ready_ledger = Some(e.ledger().sequence().saturating_add(config.delay));
+    }
+    guardians.proposals.set(id, &recovery);
+    recovery
+}
+
+/// The recovery `id`, if its delay has passed.
+pub fn require_ready(e: &Env, id: &BytesN<32>) -> ActiveRecovery {
+    let recovery = require_active(e, id);
+    match recovery.ready_ledger {
+        Some(ready_ledger) if e.ledger().sequence() >= ready_ledger => recovery,
+        _ => panic_with_error!(e, G2CAccountError::RecoveryNotReady),
+    }
+}
+
+pub fn require_active(e: &Env, id: &BytesN<32>) -> ActiveRecovery {
+    active(e, id).unwrap_or_else(|| panic_with_error!(e, G2CAccountError::NoActiveRecovery))
+}
+
+/// Retire every open recovery and invalidate approvals signed for them.
+/// Their payloads include the old nonce, so they can no longer be looked up
+/// and their entries are left to expire.
+pub fn end(e: &Env) {
+    let guardians = Guardians::new(e);
+    guardians.recovery_nonce.set(&(nonce(e) + 1));
+}
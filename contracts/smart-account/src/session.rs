@@ -0,0 +1,61 @@
+//! Session keys scoped to a single contract.
+//!
+//! A session is a `CallContract` context rule whose only signer is a
+//! short-lived key checked by the account's session verifier. It lapses at its
+//! `valid_until` ledger, so a dApp can sign for the user after one passkey
+//! approval without being able to touch anything else.
+//!
+//! A session on the account itself would let its key authorize `execute`,
+//! `schedule` or `grant_session`, and one without a near expiry would be a
+//! permanent signer, so both are rejected.
+
+use soroban_sdk::{panic_with_error, Address, Env};
+use soroban_sdk_tools::{contractstorage, InstanceItem, PersistentMap};
+
+use crate::error::G2CAccountError;
+
+/// Longest a session may last: about a week at five-second ledgers.
+pub const MAX_SESSION_LEDGERS: u32 = 120_960;
+
+#[contractstorage]
+pub struct Sessions {
+    session_verifier: InstanceItem<Address>,
+    // Context rules created by `grant_session`.
+    session_rules: PersistentMap<u32, bool>,
+}
+
+pub fn verifier(e: &Env) -> Option<Address> {
+    Sessions::new(e).session_verifier.get()
+}
+
+pub fn set_verifier(e: &Env, verifier: &Address) {
+    Sessions::new(e).session_verifier.set(verifier);
+}
+
+pub fn require_verifier(e: &Env) -> Address {
+    verifier(e).unwrap_or_else(|| panic_with_error!(e, G2CAccountError::NoSessionVerifier))
+}
+
+/// Fail unless a session for `contract` until `valid_until` is allowed.
+pub fn validate(e: &Env, contract: &Address, valid_until: u32) {
+    let now = e.ledger().sequence();
+    if *contract == e.current_contract_address()
+        || valid_until <= now
+        || valid_until > now.saturating_add(MAX_SESSION_LEDGERS)
+    {
+        panic_with_error!(e, G2CAccountError::InvalidSession);
+    }
+}
+
+pub fn record(e: &Env, context_rule_id: u32) {
+    Sessions::new(e).session_rules.set(&context_rule_id, &true);
+}
+
+/// Forget session rule `context_rule_id`, failing if it is not one.
+pub fn take(e: &Env, context_rule_id: u32) {
+    let sessions = Sessions::new(e);
+    if sessions.session_rules.get(&context_rule_id).is_none() {
+        panic_with_error!(e, G2CAccountError::NotASession);
+    }
+    sessions.session_rules.remove(&context_rule_id);
+}
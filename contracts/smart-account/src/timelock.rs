@@ -0,0 +1,128 @@
+//! Delay for sensitive admin operations.
+//!
+//! While the delay is non-zero, signer, policy, rule, guardian and session
+//! verifier changes and upgrades can only be scheduled. They become executable `admin_delay`
+//! ledgers later, and until then any signer on the default rule can cancel
+//! them. A stolen passkey therefore cannot lock the owner out before they
+//! notice.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, BytesN, Env, Val, Vec};
+use soroban_sdk_tools::{contractstorage, InstanceItem, PersistentMap};
+use stellar_accounts::smart_account::Signer;
+
+use crate::{contract::ContextRuleParams, error::G2CAccountError, recovery::RecoveryConfig};
+
+/// A sensitive account change that has to wait out the admin delay.
+#[contracttype]
+#[derive(Clone)]
+pub enum AdminOp {
+    AddSigner(u32, Signer),
+    RemoveSigner(u32, Signer),
+    AddPolicy(u32, Address, Val),
+    RemovePolicy(u32, Address),
+    AddContextRule(ContextRuleParams),
+    RemoveContextRule(u32),
+    UpdateValidUntil(u32, Option<u32>),
+    Upgrade(BytesN<32>),
+    SetUpgradeAuthority(Option<Address>),
+    SetDelay(u32),
+    SetRecovery(Option<RecoveryConfig>),
+    SetSessionVerifier(Address),
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct ScheduledOperation {
+    pub id: u32,
+    pub operation: AdminOp,
+    /// First ledger at which the operation can be executed.
+    pub ready_ledger: u32,
+}
+
+#[contractstorage]
+pub struct Timelock {
+    // Ledgers between scheduling and executing an admin operation.
+    admin_delay: InstanceItem<u32>,
+    next_operation_id: InstanceItem<u32>,
+    operations: PersistentMap<u32, ScheduledOperation>,
+    // Ids of `operations`, in scheduling order.
+    pending: InstanceItem<Vec<u32>>,
+}
+
+pub fn delay(e: &Env) -> u32 {
+    Timelock::new(e).admin_delay.get().unwrap_or_default()
+}
+
+pub fn set_delay(e: &Env, ledgers: u32) {
+    Timelock::new(e).admin_delay.set(&ledgers);
+}
+
+/// Fail if sensitive operations must be scheduled instead of run directly.
+pub fn require_no_delay(e: &Env) {
+    if delay(e) > 0 {
+        panic_with_error!(e, G2CAccountError::DelayActive);
+    }
+}
+
+pub fn schedule(e: &Env, operation: AdminOp) -> ScheduledOperation {
+    let timelock = Timelock::new(e);
+    let id = timelock.next_operation_id.get().unwrap_or_default();
+    let scheduled = ScheduledOperation {
+        id,
+        operation,
+        ready_ledger: e.ledger().sequence().saturating_add(delay(e)),
+    };
+    timelock.next_operation_id.set(&(id + 1));
+    timelock.operations.set(&id, &scheduled);
+    let mut pending = pending_ids(e);
+    pending.push_back(id);
+    timelock.pending.set(&pending);
+    scheduled
+}
+
+pub fn get(e: &Env, id: u32) -> Option<ScheduledOperation> {
+    Timelock::new(e).operations.get(&id)
+}
+
+pub fn pending(e: &Env) -> Vec<ScheduledOperation> {
+    let operations = Timelock::new(e).operations;
+    let mut pending = Vec::new(e);
+    for id in pending_ids(e) {
+        if let Some(operation) = operations.get(&id) {
+            pending.push_back(operation);
+        }
+    }
+    pending
+}
+
+/// Remove a scheduled operation, whether or not it is ready.
+pub fn take(e: &Env, id: u32) -> ScheduledOperation {
+    let timelock = Timelock::new(e);
+    let Some(scheduled) = timelock.operations.get(&id) else {
+        panic_with_error!(e, G2CAccountError::OperationNotFound)
+    };
+    timelock.operations.remove(&id);
+    let mut pending = pending_ids(e);
+    if let Some(i) = pending.first_index_of(id) {
+        pending.remove(i);
+        timelock.pending.set(&pending);
+    }
+    scheduled
+}
+
+/// Remove a scheduled operation that is ready to execute.
+pub fn take_ready(e: &Env, id: u32) -> ScheduledOperation {
+    let scheduled =
+        get(e, id).unwrap_or_else(|| panic_with_error!(e, G2CAccountError::OperationNotFound));
+    if e.ledger().sequence() < scheduled.ready_ledger {
+        panic_with_error!(e, G2CAccountError::OperationNotReady);
+    }
+    take(e, id)
+}
+
+fn pending_ids(e: &Env) -> Vec<u32> {
+    Timelock::new(e)
+        .pending
+        .get()
+        .unwrap_or_else(|| Vec::new(e))
+}
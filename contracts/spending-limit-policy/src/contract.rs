@@ -0,0 +1,93 @@
+use soroban_sdk::{auth::Context, contract, contractimpl, Address, Env, Map, Vec};
+use stellar_accounts::{
+    policies::Policy,
+    smart_account::{ContextRule, Signer},
+};
+
+use crate::spending::{self, SpendingLimit};
+
+/// Smart account policy capping how much of each token a context rule may
+/// move per rolling period.
+///
+/// Install it on a rule with `add_policy(rule_id, policy, limits)`, where
+/// `limits` maps token addresses to a `SpendingLimit`. The policy only limits
+/// spending: it accepts any call with at least one authenticated signer, so
+/// rules with several signers should pair it with a threshold policy.
+///
+/// Installing again on a rule that already has limits is refused, since it
+/// would reset the period's spends; remove the policy first. The G2C account
+/// only authorizes `install` and `uninstall` from its own policy management
+/// functions, never through `execute` or a signed auth entry.
+#[contract]
+pub struct SpendingLimitPolicy;
+
+#[contractimpl]
+impl Policy for SpendingLimitPolicy {
+    type AccountParams = Map<Address, SpendingLimit>;
+
+    fn can_enforce(
+        e: &Env,
+        context: Context,
+        authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) -> bool {
+        !authenticated_signers.is_empty()
+            && spending::allows(e, &smart_account, context_rule.id, &context)
+    }
+
+    fn enforce(
+        e: &Env,
+        context: Context,
+        _authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        smart_account.require_auth();
+        spending::record(e, &smart_account, context_rule.id, &context);
+    }
+
+    fn install(
+        e: &Env,
+        install_params: Self::AccountParams,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        smart_account.require_auth();
+        spending::install(e, &smart_account, context_rule.id, install_params);
+    }
+
+    fn uninstall(e: &Env, context_rule: ContextRule, smart_account: Address) {
+        smart_account.require_auth();
+        spending::uninstall(e, &smart_account, context_rule.id);
+    }
+}
+
+#[contractimpl]
+impl SpendingLimitPolicy {
+    /// The limits installed for `smart_account`'s context rule.
+    pub fn get_limits(
+        e: &Env,
+        smart_account: Address,
+        context_rule_id: u32,
+    ) -> Map<Address, SpendingLimit> {
+        let mut limits = Map::new(e);
+        for (token, usage) in spending::usage(e, &smart_account, context_rule_id) {
+            limits.set(token, usage.limit);
+        }
+        limits
+    }
+
+    /// How much of `token` the rule can still spend in the current period, or
+    /// `None` if the token is not limited.
+    pub fn get_remaining(
+        e: &Env,
+        smart_account: Address,
+        context_rule_id: u32,
+        token: Address,
+    ) -> Option<i128> {
+        spending::usage(e, &smart_account, context_rule_id)
+            .get(token)
+            .map(|usage| spending::remaining(e, &usage))
+    }
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SpendingLimitError {
+    /// A limit or its period is zero or negative.
+    InvalidLimit = 1,
+    /// The call would spend more than is left in the current period.
+    LimitExceeded = 2,
+    /// The policy is already installed on this context rule.
+    AlreadyInstalled = 3,
+}
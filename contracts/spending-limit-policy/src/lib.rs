@@ -0,0 +1,6 @@
+#![no_std]
+#![allow(dead_code)]
+
+mod contract;
+mod error;
+mod spending;
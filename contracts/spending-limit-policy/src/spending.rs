@@ -0,0 +1,235 @@
+//! Per-token spending caps over a rolling window of ledgers.
+//!
+//! Each account's context rule keeps its own limits and the spends that are
+//! still inside their window. A spend is the `amount` argument (index 2) of a
+//! SEP-41 `transfer(from, to, amount)` or
+//! `approve(from, spender, amount, expiration_ledger)` call on a limited
+//! token whose `from` is the account; calls to other contracts or functions
+//! are not limited.
+//!
+//! Calls the account makes through its own `execute`, `try_invoke` or
+//! `execute_batch` only show up as a context on the account, since the
+//! account authorizes the inner calls as their invoker. Those contexts are
+//! unpacked so the calls they run count too, summed per token for a batch.
+
+use soroban_sdk::{
+    auth::{Context, ContractContext},
+    contracttype, panic_with_error, symbol_short, Address, Env, Map, Symbol, TryFromVal, Val, Vec,
+};
+use soroban_sdk_tools::{contractstorage, PersistentMap};
+
+use crate::error::SpendingLimitError;
+
+const TRANSFER: Symbol = symbol_short!("transfer");
+const APPROVE: Symbol = symbol_short!("approve");
+const EXECUTE: Symbol = symbol_short!("execute");
+
+/// How much of a token may be spent within `period_ledgers` ledgers.
+#[contracttype]
+#[derive(Clone)]
+pub struct SpendingLimit {
+    pub limit: i128,
+    pub period_ledgers: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct Spend {
+    pub ledger: u32,
+    pub amount: i128,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct TokenUsage {
+    pub limit: SpendingLimit,
+    /// Spends recorded within the last `period_ledgers` ledgers.
+    pub spends: Vec<Spend>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RuleKey {
+    pub account: Address,
+    pub context_rule_id: u32,
+}
+
+#[contractstorage]
+pub struct Limits {
+    // Per-token limits and recent spends of each installed context rule.
+    token_usage: PersistentMap<RuleKey, Map<Address, TokenUsage>>,
+}
+
+fn key(account: &Address, context_rule_id: u32) -> RuleKey {
+    RuleKey {
+        account: account.clone(),
+        context_rule_id,
+    }
+}
+
+pub fn usage(e: &Env, account: &Address, context_rule_id: u32) -> Map<Address, TokenUsage> {
+    Limits::new(e)
+        .token_usage
+        .get(&key(account, context_rule_id))
+        .unwrap_or_else(|| Map::new(e))
+}
+
+pub fn install(
+    e: &Env,
+    account: &Address,
+    context_rule_id: u32,
+    limits: Map<Address, SpendingLimit>,
+) {
+    let token_usage = Limits::new(e).token_usage;
+    let rule = key(account, context_rule_id);
+    // Reinstalling would wipe the spends recorded in the current period.
+    if token_usage.get(&rule).is_some() {
+        panic_with_error!(e, SpendingLimitError::AlreadyInstalled);
+    }
+    let mut usage = Map::new(e);
+    for (token, limit) in limits {
+        if limit.limit <= 0 || limit.period_ledgers == 0 {
+            panic_with_error!(e, SpendingLimitError::InvalidLimit);
+        }
+        usage.set(
+            token,
+            TokenUsage {
+                limit,
+                spends: Vec::new(e),
+            },
+        );
+    }
+    token_usage.set(&rule, &usage);
+}
+
+pub fn uninstall(e: &Env, account: &Address, context_rule_id: u32) {
+    Limits::new(e)
+        .token_usage
+        .remove(&key(account, context_rule_id));
+}
+
+/// The amounts `context` spends from `account`, summed per token. Amounts
+/// that are negative or not an `i128` are treated as unbounded, so they
+/// never fit a limit.
+fn spends_of(e: &Env, account: &Address, context: &Context) -> Map<Address, i128> {
+    let mut spends = Map::new(e);
+    let Context::Contract(ContractContext {
+        contract,
+        fn_name,
+        args,
+    }) = context
+    else {
+        return spends;
+    };
+    if contract == account {
+        for (target, target_fn, target_args) in account_calls(e, fn_name, args) {
+            add_spend(e, &mut spends, account, &target, &target_fn, &target_args);
+        }
+    } else {
+        add_spend(e, &mut spends, account, contract, fn_name, args);
+    }
+    spends
+}
+
+/// The calls an `execute`, `try_invoke` or `execute_batch` context on the
+/// account itself runs. Its other functions make no calls on its behalf.
+fn account_calls(e: &Env, fn_name: &Symbol, args: &Vec<Val>) -> Vec<(Address, Symbol, Vec<Val>)> {
+    if *fn_name == Symbol::new(e, "execute_batch") {
+        return args
+            .get(0)
+            .and_then(|calls| Vec::try_from_val(e, &calls).ok())
+            .unwrap_or_else(|| Vec::new(e));
+    }
+    let mut calls = Vec::new(e);
+    if *fn_name == EXECUTE || *fn_name == Symbol::new(e, "try_invoke") {
+        let target = args.get(0).and_then(|v| Address::try_from_val(e, &v).ok());
+        let target_fn = args.get(1).and_then(|v| Symbol::try_from_val(e, &v).ok());
+        let target_args = args.get(2).and_then(|v| Vec::try_from_val(e, &v).ok());
+        if let (Some(target), Some(target_fn), Some(target_args)) = (target, target_fn, target_args)
+        {
+            calls.push_back((target, target_fn, target_args));
+        }
+    }
+    calls
+}
+
+/// Add the spend of a `target_fn(args)` call on `token`, if it is a
+/// `transfer` or `approve` from `account`.
+fn add_spend(
+    e: &Env,
+    spends: &mut Map<Address, i128>,
+    account: &Address,
+    token: &Address,
+    target_fn: &Symbol,
+    args: &Vec<Val>,
+) {
+    if *target_fn != TRANSFER && *target_fn != APPROVE {
+        return;
+    }
+    let from = args
+        .get(0)
+        .and_then(|from| Address::try_from_val(e, &from).ok());
+    if from.as_ref() != Some(account) {
+        return;
+    }
+    let amount = args
+        .get(2)
+        .and_then(|amount| i128::try_from_val(e, &amount).ok())
+        .filter(|amount| *amount >= 0)
+        .unwrap_or(i128::MAX);
+    let total = spends.get(token.clone()).unwrap_or(0);
+    spends.set(token.clone(), total.saturating_add(amount));
+}
+
+/// What is left of `usage`'s limit in the current window.
+pub fn remaining(e: &Env, usage: &TokenUsage) -> i128 {
+    let now = e.ledger().sequence();
+    let spent = usage
+        .spends
+        .iter()
+        .filter(|spend| spend.ledger.saturating_add(usage.limit.period_ledgers) > now)
+        .fold(0i128, |total, spend| total.saturating_add(spend.amount));
+    usage.limit.limit.saturating_sub(spent)
+}
+
+/// Whether `context` stays within the rule's limits.
+pub fn allows(e: &Env, account: &Address, context_rule_id: u32, context: &Context) -> bool {
+    let all_usage = usage(e, account, context_rule_id);
+    spends_of(e, account, context)
+        .iter()
+        .all(|(token, amount)| match all_usage.get(token) {
+            Some(usage) => amount <= remaining(e, &usage),
+            None => true,
+        })
+}
+
+/// Record the spend in `context` against the rule's limits, dropping spends
+/// that have left the window.
+pub fn record(e: &Env, account: &Address, context_rule_id: u32, context: &Context) {
+    if !allows(e, account, context_rule_id, context) {
+        panic_with_error!(e, SpendingLimitError::LimitExceeded);
+    }
+    let mut all_usage = usage(e, account, context_rule_id);
+    let now = e.ledger().sequence();
+    for (token, amount) in spends_of(e, account, context) {
+        let Some(mut usage) = all_usage.get(token.clone()) else {
+            continue;
+        };
+        let period_ledgers = usage.limit.period_ledgers;
+        let mut spends = Vec::new(e);
+        for spend in usage.spends.iter() {
+            if spend.ledger.saturating_add(period_ledgers) > now {
+                spends.push_back(spend);
+            }
+        }
+        spends.push_back(Spend {
+            ledger: now,
+            amount,
+        });
+        usage.spends = spends;
+        all_usage.set(token, usage);
+    }
+    Limits::new(e)
+        .token_usage
+        .set(&key(account, context_rule_id), &all_usage);
+}
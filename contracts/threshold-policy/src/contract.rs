@@ -0,0 +1,78 @@
+use soroban_sdk::{auth::Context, contract, contractimpl, panic_with_error, Address, Env, Vec};
+use stellar_accounts::{
+    policies::Policy,
+    smart_account::{ContextRule, Signer},
+};
+
+use crate::{
+    error::ThresholdError,
+    threshold::{self, ThresholdParams},
+};
+
+/// Smart account policy requiring M of a context rule's N signers, by count
+/// or by weight.
+///
+/// Install it on a rule with `add_policy(rule_id, policy, params)`. The
+/// threshold is checked against the rule's signers at install time only; if
+/// signers are removed later, reinstall the policy with a reachable one by
+/// removing it and adding it again; installing over an existing threshold is
+/// refused. The G2C account only authorizes `install` and `uninstall` from its
+/// own policy management functions, so signers cannot lower the threshold
+/// through `execute`.
+#[contract]
+pub struct ThresholdPolicy;
+
+#[contractimpl]
+impl Policy for ThresholdPolicy {
+    type AccountParams = ThresholdParams;
+
+    fn can_enforce(
+        e: &Env,
+        _context: Context,
+        authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) -> bool {
+        threshold::is_met(e, &smart_account, &context_rule, &authenticated_signers)
+    }
+
+    fn enforce(
+        e: &Env,
+        _context: Context,
+        authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        smart_account.require_auth();
+        if !threshold::is_met(e, &smart_account, &context_rule, &authenticated_signers) {
+            panic_with_error!(e, ThresholdError::ThresholdNotMet);
+        }
+    }
+
+    fn install(
+        e: &Env,
+        install_params: Self::AccountParams,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        smart_account.require_auth();
+        threshold::install(e, &smart_account, &context_rule, install_params);
+    }
+
+    fn uninstall(e: &Env, context_rule: ContextRule, smart_account: Address) {
+        smart_account.require_auth();
+        threshold::uninstall(e, &smart_account, context_rule.id);
+    }
+}
+
+#[contractimpl]
+impl ThresholdPolicy {
+    /// The threshold installed for `smart_account`'s context rule.
+    pub fn get_threshold(
+        e: &Env,
+        smart_account: Address,
+        context_rule_id: u32,
+    ) -> Option<ThresholdParams> {
+        threshold::get(e, &smart_account, context_rule_id)
+    }
+}
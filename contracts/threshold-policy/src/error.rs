@@ -0,0 +1,13 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ThresholdError {
+    /// The threshold is zero or more than the rule's signers can reach.
+    InvalidThreshold = 1,
+    /// Too few signers (or too little weight) authenticated.
+    ThresholdNotMet = 2,
+    /// The policy is already installed on this context rule.
+    AlreadyInstalled = 3,
+}
@@ -0,0 +1,109 @@
+//! M-of-N signer thresholds per account context rule.
+//!
+//! A rule's policy decides on its own whether a context is authorized, so
+//! without a threshold policy any one authenticated signer of a multi-signer
+//! rule is enough.
+
+use soroban_sdk::{contracttype, panic_with_error, Address, Env, Map, Vec};
+use soroban_sdk_tools::{contractstorage, PersistentMap};
+use stellar_accounts::smart_account::{ContextRule, Signer};
+
+use crate::error::ThresholdError;
+
+#[contracttype]
+#[derive(Clone)]
+pub enum ThresholdParams {
+    /// At least this many of the rule's signers.
+    Simple(u32),
+    /// Signers' weights, and the total weight needed. Signers missing from
+    /// the map weigh nothing.
+    Weighted(Map<Signer, u32>, u32),
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct RuleKey {
+    pub account: Address,
+    pub context_rule_id: u32,
+}
+
+#[contractstorage]
+pub struct Thresholds {
+    // Threshold of each installed context rule.
+    thresholds: PersistentMap<RuleKey, ThresholdParams>,
+}
+
+fn key(account: &Address, context_rule_id: u32) -> RuleKey {
+    RuleKey {
+        account: account.clone(),
+        context_rule_id,
+    }
+}
+
+pub fn get(e: &Env, account: &Address, context_rule_id: u32) -> Option<ThresholdParams> {
+    Thresholds::new(e)
+        .thresholds
+        .get(&key(account, context_rule_id))
+}
+
+/// Store `params` for the rule, checking the rule's current signers can meet
+/// them and that the rule has no threshold yet.
+pub fn install(e: &Env, account: &Address, context_rule: &ContextRule, params: ThresholdParams) {
+    if get(e, account, context_rule.id).is_some() {
+        panic_with_error!(e, ThresholdError::AlreadyInstalled);
+    }
+    let reachable = match &params {
+        ThresholdParams::Simple(threshold) => {
+            *threshold > 0 && *threshold <= context_rule.signers.len()
+        }
+        ThresholdParams::Weighted(_, threshold) => {
+            *threshold > 0 && weight(&params, &context_rule.signers) >= *threshold
+        }
+    };
+    if !reachable {
+        panic_with_error!(e, ThresholdError::InvalidThreshold);
+    }
+    Thresholds::new(e)
+        .thresholds
+        .set(&key(account, context_rule.id), &params);
+}
+
+pub fn uninstall(e: &Env, account: &Address, context_rule_id: u32) {
+    Thresholds::new(e)
+        .thresholds
+        .remove(&key(account, context_rule_id));
+}
+
+/// The count or total weight of `signers` under `params`.
+fn weight(params: &ThresholdParams, signers: &Vec<Signer>) -> u32 {
+    match params {
+        ThresholdParams::Simple(_) => signers.len(),
+        ThresholdParams::Weighted(weights, _) => signers
+            .iter()
+            .map(|signer| weights.get(signer).unwrap_or(0))
+            .fold(0u32, u32::saturating_add),
+    }
+}
+
+/// Whether the authenticated signers meet the rule's threshold. Only signers
+/// on the rule count.
+pub fn is_met(
+    e: &Env,
+    account: &Address,
+    context_rule: &ContextRule,
+    authenticated_signers: &Vec<Signer>,
+) -> bool {
+    let Some(params) = get(e, account, context_rule.id) else {
+        return false;
+    };
+    let mut signers = Vec::new(e);
+    for signer in authenticated_signers.iter() {
+        if context_rule.signers.contains(&signer) && !signers.contains(&signer) {
+            signers.push_back(signer);
+        }
+    }
+    let threshold = match params {
+        ThresholdParams::Simple(threshold) | ThresholdParams::Weighted(_, threshold) => threshold,
+    };
+    weight(&params, &signers) >= threshold
+}
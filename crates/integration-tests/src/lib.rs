@@ -2,7 +2,8 @@ use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 use base64::Engine;
 use p256::ecdsa::signature::hazmat::PrehashSigner;
 use p256::ecdsa::{Signature, SigningKey};
-use stellar_accounts::smart_account::{ContextRule, Signer};
+use stellar_accounts::smart_account::{ContextRule, ContextRuleType, Signer};
+use stellar_accounts::verifiers::webauthn::WebAuthnSigData;
 
 pub const SMART_ACCOUNT_WASM: &[u8] =
     include_bytes!("../../../target/wasm32v1-none/contract/g2c_smart_account.wasm");
@@ -10,11 +11,429 @@ pub const SMART_ACCOUNT_WASM: &[u8] =
 pub const WEBAUTHN_VERIFIER_WASM: &[u8] =
     include_bytes!("../../../target/wasm32v1-none/contract/g2c_webauthn_verifier.wasm");
 
+pub const ED25519_VERIFIER_WASM: &[u8] =
+    include_bytes!("../../../target/wasm32v1-none/contract/g2c_ed25519_verifier.wasm");
+
+pub const FACTORY_WASM: &[u8] =
+    include_bytes!("../../../target/wasm32v1-none/contract/g2c_factory.wasm");
+
+pub const SPENDING_LIMIT_POLICY_WASM: &[u8] =
+    include_bytes!("../../../target/wasm32v1-none/contract/g2c_spending_limit_policy.wasm");
+
+pub const THRESHOLD_POLICY_WASM: &[u8] =
+    include_bytes!("../../../target/wasm32v1-none/contract/g2c_threshold_policy.wasm");
+
 #[allow(dead_code)]
 #[soroban_sdk::contractclient(name = "SmartAccountClient")]
 trait SmartAccountInterface {
     fn get_context_rule(env: soroban_sdk::Env, context_rule_id: u32) -> ContextRule;
     fn get_context_rules_count(env: soroban_sdk::Env) -> u32;
+    fn get_credential_ids(
+        env: soroban_sdk::Env,
+        context_rule_id: u32,
+    ) -> soroban_sdk::Vec<soroban_sdk::Bytes>;
+    fn upgrade(env: soroban_sdk::Env, new_wasm_hash: soroban_sdk::BytesN<32>);
+    fn migrate(env: soroban_sdk::Env);
+    fn get_schema_version(env: soroban_sdk::Env) -> u32;
+    fn set_upgrade_authority(env: soroban_sdk::Env, authority: Option<soroban_sdk::Address>);
+    fn get_upgrade_authority(env: soroban_sdk::Env) -> Option<soroban_sdk::Address>;
+    fn add_signer(env: soroban_sdk::Env, context_rule_id: u32, signer: Signer);
+    fn remove_signer(env: soroban_sdk::Env, context_rule_id: u32, signer: Signer);
+    fn add_policy(
+        env: soroban_sdk::Env,
+        context_rule_id: u32,
+        policy: soroban_sdk::Address,
+        install_param: soroban_sdk::Val,
+    );
+    fn remove_policy(env: soroban_sdk::Env, context_rule_id: u32, policy: soroban_sdk::Address);
+    fn set_admin_delay(env: soroban_sdk::Env, ledgers: u32);
+    fn get_admin_delay(env: soroban_sdk::Env) -> u32;
+    fn schedule(env: soroban_sdk::Env, operation: AdminOp) -> u32;
+    fn execute_scheduled(env: soroban_sdk::Env, id: u32);
+    fn cancel_scheduled(
+        env: soroban_sdk::Env,
+        id: u32,
+        signer: Signer,
+        sig_data: soroban_sdk::Bytes,
+    );
+    fn cancel_payload(env: soroban_sdk::Env, id: u32) -> soroban_sdk::BytesN<32>;
+    fn get_scheduled_operation(env: soroban_sdk::Env, id: u32) -> Option<ScheduledOperation>;
+    fn get_pending_operations(env: soroban_sdk::Env) -> soroban_sdk::Vec<ScheduledOperation>;
+    fn set_recovery(env: soroban_sdk::Env, config: Option<RecoveryConfig>);
+    fn get_recovery(env: soroban_sdk::Env) -> Option<RecoveryConfig>;
+    fn get_active_recovery(
+        env: soroban_sdk::Env,
+        new_signers: soroban_sdk::Vec<Signer>,
+    ) -> Option<ActiveRecovery>;
+    fn initiate_recovery(
+        env: soroban_sdk::Env,
+        guardian: Signer,
+        new_signers: soroban_sdk::Vec<Signer>,
+        sig_data: soroban_sdk::Bytes,
+    );
+    fn approve_recovery(
+        env: soroban_sdk::Env,
+        guardian: Signer,
+        new_signers: soroban_sdk::Vec<Signer>,
+        sig_data: soroban_sdk::Bytes,
+    );
+    fn execute_recovery(env: soroban_sdk::Env, new_signers: soroban_sdk::Vec<Signer>);
+    fn cancel_recovery(env: soroban_sdk::Env);
+    fn execute(
+        env: soroban_sdk::Env,
+        target: soroban_sdk::Address,
+        target_fn: soroban_sdk::Symbol,
+        target_args: soroban_sdk::Vec<soroban_sdk::Val>,
+    ) -> soroban_sdk::Val;
+    fn try_invoke(
+        env: soroban_sdk::Env,
+        target: soroban_sdk::Address,
+        target_fn: soroban_sdk::Symbol,
+        target_args: soroban_sdk::Vec<soroban_sdk::Val>,
+    ) -> Result<CallResult, soroban_sdk::Error>;
+    fn execute_batch(
+        env: soroban_sdk::Env,
+        calls: soroban_sdk::Vec<(
+            soroban_sdk::Address,
+            soroban_sdk::Symbol,
+            soroban_sdk::Vec<soroban_sdk::Val>,
+        )>,
+    ) -> soroban_sdk::Vec<soroban_sdk::Val>;
+    fn set_session_verifier(env: soroban_sdk::Env, verifier: soroban_sdk::Address);
+    fn get_session_verifier(env: soroban_sdk::Env) -> Option<soroban_sdk::Address>;
+    fn grant_session(
+        env: soroban_sdk::Env,
+        contract: soroban_sdk::Address,
+        key: soroban_sdk::BytesN<32>,
+        valid_until: u32,
+        limits: soroban_sdk::Map<soroban_sdk::Address, soroban_sdk::Val>,
+    ) -> u32;
+    fn revoke_session(env: soroban_sdk::Env, context_rule_id: u32);
+    fn recovery_payload(
+        env: soroban_sdk::Env,
+        new_signers: soroban_sdk::Vec<Signer>,
+    ) -> soroban_sdk::BytesN<32>;
+}
+
+#[allow(dead_code)]
+#[soroban_sdk::contractclient(name = "FactoryClient")]
+trait FactoryInterface {
+    fn create_account(
+        env: soroban_sdk::Env,
+        funder: soroban_sdk::Address,
+        key: soroban_sdk::BytesN<65>,
+        credential_id: Option<soroban_sdk::Bytes>,
+        amount: i128,
+        index: Option<u32>,
+    ) -> soroban_sdk::Address;
+    fn create_account_with_assets(
+        env: soroban_sdk::Env,
+        funder: soroban_sdk::Address,
+        key: soroban_sdk::BytesN<65>,
+        credential_id: Option<soroban_sdk::Bytes>,
+        assets: soroban_sdk::Vec<(soroban_sdk::Address, i128)>,
+        index: Option<u32>,
+    ) -> soroban_sdk::Address;
+    fn create_account_with_config(
+        env: soroban_sdk::Env,
+        funder: soroban_sdk::Address,
+        config: AccountConfig,
+        amount: i128,
+        index: Option<u32>,
+    ) -> soroban_sdk::Address;
+    fn create_accounts(
+        env: soroban_sdk::Env,
+        funder: soroban_sdk::Address,
+        entries: soroban_sdk::Vec<(
+            soroban_sdk::BytesN<65>,
+            Option<soroban_sdk::Bytes>,
+            i128,
+            u32,
+        )>,
+    ) -> soroban_sdk::Vec<soroban_sdk::Address>;
+    fn get_c_address(
+        env: soroban_sdk::Env,
+        funder: soroban_sdk::Address,
+        index: Option<u32>,
+    ) -> soroban_sdk::Address;
+    fn predict_address(
+        env: soroban_sdk::Env,
+        key: soroban_sdk::BytesN<65>,
+        credential_id: Option<soroban_sdk::Bytes>,
+    ) -> soroban_sdk::Address;
+    fn create_account_for_key(
+        env: soroban_sdk::Env,
+        key: soroban_sdk::BytesN<65>,
+        credential_id: Option<soroban_sdk::Bytes>,
+        funder: soroban_sdk::Address,
+        amount: i128,
+    ) -> soroban_sdk::Address;
+    fn create_account_sponsored(
+        env: soroban_sdk::Env,
+        relayer: soroban_sdk::Address,
+        funder: soroban_sdk::Address,
+        key: soroban_sdk::BytesN<65>,
+        credential_id: Option<soroban_sdk::Bytes>,
+        amount: i128,
+    ) -> soroban_sdk::Address;
+    fn deposit_gift(
+        env: soroban_sdk::Env,
+        sender: soroban_sdk::Address,
+        token: soroban_sdk::Address,
+        amount: i128,
+        claim_key: soroban_sdk::BytesN<32>,
+        expiry_ledger: u32,
+    );
+    fn claim_gift(
+        env: soroban_sdk::Env,
+        claim_key: soroban_sdk::BytesN<32>,
+        key: soroban_sdk::BytesN<65>,
+        credential_id: Option<soroban_sdk::Bytes>,
+        signature: soroban_sdk::BytesN<64>,
+    ) -> soroban_sdk::Address;
+    fn gift_claim_payload(
+        env: soroban_sdk::Env,
+        claim_key: soroban_sdk::BytesN<32>,
+        key: soroban_sdk::BytesN<65>,
+        credential_id: Option<soroban_sdk::Bytes>,
+    ) -> soroban_sdk::Bytes;
+    fn refund_gift(env: soroban_sdk::Env, claim_key: soroban_sdk::BytesN<32>);
+    fn get_gift(env: soroban_sdk::Env, claim_key: soroban_sdk::BytesN<32>) -> Option<Gift>;
+    fn get_account_indices(
+        env: soroban_sdk::Env,
+        funder: soroban_sdk::Address,
+    ) -> soroban_sdk::Vec<u32>;
+    fn lookup_by_key(
+        env: soroban_sdk::Env,
+        key: soroban_sdk::BytesN<65>,
+        start: u32,
+        limit: u32,
+    ) -> soroban_sdk::Vec<soroban_sdk::Address>;
+    fn lookup_by_credential(
+        env: soroban_sdk::Env,
+        credential_id: soroban_sdk::Bytes,
+        start: u32,
+        limit: u32,
+    ) -> soroban_sdk::Vec<soroban_sdk::Address>;
+    fn register_signer(
+        env: soroban_sdk::Env,
+        account: soroban_sdk::Address,
+        key_data: soroban_sdk::Bytes,
+    );
+    fn unregister_signer(
+        env: soroban_sdk::Env,
+        account: soroban_sdk::Address,
+        key_data: soroban_sdk::Bytes,
+    );
+    fn set_account_wasm(env: soroban_sdk::Env, wasm_hash: soroban_sdk::BytesN<32>) -> u32;
+    fn set_verifier_wasm(env: soroban_sdk::Env, wasm_hash: soroban_sdk::BytesN<32>) -> u32;
+    fn set_guardian(env: soroban_sdk::Env, guardian: Option<soroban_sdk::Address>);
+    fn pause(env: soroban_sdk::Env, caller: soroban_sdk::Address);
+    fn unpause(env: soroban_sdk::Env);
+    fn is_paused(env: soroban_sdk::Env) -> bool;
+    fn get_guardian(env: soroban_sdk::Env) -> Option<soroban_sdk::Address>;
+    fn register_verifier(
+        env: soroban_sdk::Env,
+        kind: soroban_sdk::Symbol,
+        wasm_hash: soroban_sdk::BytesN<32>,
+    );
+    fn remove_verifier(env: soroban_sdk::Env, kind: soroban_sdk::Symbol);
+    fn get_verifier_kind_wasm(
+        env: soroban_sdk::Env,
+        kind: soroban_sdk::Symbol,
+    ) -> Option<soroban_sdk::BytesN<32>>;
+    fn get_verifier_kinds(env: soroban_sdk::Env) -> soroban_sdk::Vec<soroban_sdk::Symbol>;
+    fn get_verifier_address(
+        env: soroban_sdk::Env,
+        kind: soroban_sdk::Symbol,
+    ) -> soroban_sdk::Address;
+    fn is_verifier_deployed(env: soroban_sdk::Env, kind: soroban_sdk::Symbol) -> bool;
+    fn ensure_verifier(env: soroban_sdk::Env, kind: soroban_sdk::Symbol) -> soroban_sdk::Address;
+    fn set_fee(env: soroban_sdk::Env, fee: FeeConfig, treasury: soroban_sdk::Address);
+    fn set_fee_exempt(env: soroban_sdk::Env, funder: soroban_sdk::Address, exempt: bool);
+    fn get_fee(env: soroban_sdk::Env) -> FeeConfig;
+    fn get_treasury(env: soroban_sdk::Env) -> Option<soroban_sdk::Address>;
+    fn is_fee_exempt(env: soroban_sdk::Env, funder: soroban_sdk::Address) -> bool;
+    fn funding_token(env: soroban_sdk::Env) -> soroban_sdk::Address;
+    fn get_admin(env: soroban_sdk::Env) -> soroban_sdk::Address;
+    fn get_account_wasm(env: soroban_sdk::Env) -> soroban_sdk::BytesN<32>;
+    fn get_account_wasm_version(env: soroban_sdk::Env) -> u32;
+    fn get_account_wasm_at(env: soroban_sdk::Env, version: u32) -> Option<soroban_sdk::BytesN<32>>;
+    fn is_approved_account_wasm(env: soroban_sdk::Env, wasm_hash: soroban_sdk::BytesN<32>) -> bool;
+    fn get_verifier_wasm(env: soroban_sdk::Env) -> soroban_sdk::BytesN<32>;
+    fn get_verifier_wasm_version(env: soroban_sdk::Env) -> u32;
+    fn get_verifier_wasm_at(env: soroban_sdk::Env, version: u32)
+        -> Option<soroban_sdk::BytesN<32>>;
+}
+
+/// Mirror of the smart account's `CallResult`.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug)]
+pub enum CallResult {
+    Success(soroban_sdk::Val),
+    ContractError(u32),
+}
+
+/// Mirror of the smart account's `ContextRuleParams`.
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+pub struct ContextRuleParams {
+    pub context_type: ContextRuleType,
+    pub name: soroban_sdk::String,
+    pub valid_until: Option<u32>,
+    pub signers: soroban_sdk::Vec<Signer>,
+    pub policies: soroban_sdk::Map<soroban_sdk::Address, soroban_sdk::Val>,
+}
+
+/// Mirror of the smart account's timelocked `AdminOp`.
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+pub enum AdminOp {
+    AddSigner(u32, Signer),
+    RemoveSigner(u32, Signer),
+    AddPolicy(u32, soroban_sdk::Address, soroban_sdk::Val),
+    RemovePolicy(u32, soroban_sdk::Address),
+    AddContextRule(ContextRuleParams),
+    RemoveContextRule(u32),
+    UpdateValidUntil(u32, Option<u32>),
+    Upgrade(soroban_sdk::BytesN<32>),
+    SetUpgradeAuthority(Option<soroban_sdk::Address>),
+    SetDelay(u32),
+    SetRecovery(Option<RecoveryConfig>),
+    SetSessionVerifier(soroban_sdk::Address),
+}
+
+#[allow(dead_code)]
+#[soroban_sdk::contractclient(name = "SpendingLimitPolicyClient")]
+pub trait SpendingLimitPolicyInterface {
+    fn get_limits(
+        env: soroban_sdk::Env,
+        smart_account: soroban_sdk::Address,
+        context_rule_id: u32,
+    ) -> soroban_sdk::Map<soroban_sdk::Address, SpendingLimit>;
+    fn get_remaining(
+        env: soroban_sdk::Env,
+        smart_account: soroban_sdk::Address,
+        context_rule_id: u32,
+        token: soroban_sdk::Address,
+    ) -> Option<i128>;
+}
+
+/// Mirror of the spending-limit policy's `SpendingLimit`.
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+pub struct SpendingLimit {
+    pub limit: i128,
+    pub period_ledgers: u32,
+}
+
+#[allow(dead_code)]
+#[soroban_sdk::contractclient(name = "ThresholdPolicyClient")]
+pub trait ThresholdPolicyInterface {
+    fn get_threshold(
+        env: soroban_sdk::Env,
+        smart_account: soroban_sdk::Address,
+        context_rule_id: u32,
+    ) -> Option<ThresholdParams>;
+}
+
+/// Mirror of the threshold policy's `ThresholdParams`.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ThresholdParams {
+    Simple(u32),
+    Weighted(soroban_sdk::Map<Signer, u32>, u32),
+}
+
+/// Mirror of the smart account's `RecoveryConfig`.
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+pub struct RecoveryConfig {
+    pub guardians: soroban_sdk::Vec<Signer>,
+    pub threshold: u32,
+    pub delay: u32,
+}
+
+/// Mirror of the smart account's `ActiveRecovery`.
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+pub struct ActiveRecovery {
+    pub new_signers: soroban_sdk::Vec<Signer>,
+    pub approvals: soroban_sdk::Vec<Signer>,
+    pub ready_ledger: Option<u32>,
+}
+
+/// Mirror of the smart account's `ScheduledOperation`.
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+pub struct ScheduledOperation {
+    pub id: u32,
+    pub operation: AdminOp,
+    pub ready_ledger: u32,
+}
+
+/// Mirror of the factory's `SignerSpec`.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SignerSpec {
+    WebAuthn(soroban_sdk::BytesN<65>, Option<soroban_sdk::Bytes>),
+    Ed25519(soroban_sdk::BytesN<32>),
+    Delegated(soroban_sdk::Address),
+    External(soroban_sdk::Symbol, soroban_sdk::Bytes),
+}
+
+/// Mirror of the factory's `ContextRuleSpec`.
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+pub struct ContextRuleSpec {
+    pub context_type: ContextRuleType,
+    pub name: soroban_sdk::String,
+    pub valid_until: Option<u32>,
+    pub signers: soroban_sdk::Vec<SignerSpec>,
+    pub policies: soroban_sdk::Map<soroban_sdk::Address, soroban_sdk::Val>,
+}
+
+/// Mirror of the factory's `AccountConfig`.
+#[soroban_sdk::contracttype]
+#[derive(Clone)]
+pub struct AccountConfig {
+    pub signers: soroban_sdk::Vec<SignerSpec>,
+    pub policies: soroban_sdk::Map<soroban_sdk::Address, soroban_sdk::Val>,
+    pub context_rules: soroban_sdk::Vec<ContextRuleSpec>,
+}
+
+/// Mirror of the factory's `FeeConfig`.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeConfig {
+    Disabled,
+    Flat(i128),
+    Bps(u32),
+}
+
+/// Mirror of the factory's `Gift` escrow.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Gift {
+    pub sender: soroban_sdk::Address,
+    pub token: soroban_sdk::Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub expiry_ledger: u32,
+}
+
+/// Mirror of the factory's `AccountCreated` event, for comparing against
+/// the events the factory publishes.
+#[soroban_sdk::contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountCreated {
+    #[topic]
+    pub funder: soroban_sdk::Address,
+    #[topic]
+    pub account: soroban_sdk::Address,
+    pub signer: Signer,
+    pub amount: i128,
+    pub account_wasm: soroban_sdk::BytesN<32>,
 }
 
 /// On-chain `WebAuthn` assertion components (soroban-sdk types) suitable for
@@ -80,6 +499,29 @@ pub fn build_contract_assertion(
     }
 }
 
+/// Sign `signature_payload` with a passkey and return the `Signer` and
+/// XDR-encoded `WebAuthnSigData` to put in the account's `Signatures` map.
+#[must_use]
+pub fn passkey_signature(
+    env: &soroban_sdk::Env,
+    signing_key: &SigningKey,
+    verifier: &soroban_sdk::Address,
+    signature_payload: &[u8; 32],
+) -> (Signer, soroban_sdk::Bytes) {
+    use soroban_sdk::xdr::ToXdr;
+
+    let assertion = build_contract_assertion(signing_key, env, signature_payload);
+    let sig_data = WebAuthnSigData {
+        signature: assertion.signature,
+        authenticator_data: assertion.authenticator_data,
+        client_data: assertion.client_data,
+    };
+    (
+        Signer::External(verifier.clone(), assertion.key_data),
+        sig_data.to_xdr(env),
+    )
+}
+
 /// Deploy the `WebAuthn` verifier and smart account contracts, initialising the
 /// account with a single passkey signer. Returns the client, account address,
 /// verifier address, and signing key.
@@ -105,10 +547,92 @@ pub fn deploy_smart_account(
     let signers = soroban_sdk::vec![env, signer];
     let policies: soroban_sdk::Map<soroban_sdk::Address, soroban_sdk::Val> =
         soroban_sdk::Map::new(env);
+    let context_rules: soroban_sdk::Vec<ContextRuleParams> = soroban_sdk::Vec::new(env);
 
     // Deploy the smart account with the passkey signer
-    let account_addr = env.register(SMART_ACCOUNT_WASM, (&signers, &policies));
+    let account_addr = env.register(SMART_ACCOUNT_WASM, (&signers, &policies, &context_rules));
 
     let client = SmartAccountClient::new(env, &account_addr);
     (client, account_addr, verifier_addr, signing_key)
 }
+
+/// The 65-byte SEC1 uncompressed public key of a passkey, as the factory
+/// expects it.
+#[must_use]
+pub fn passkey_public_key(
+    env: &soroban_sdk::Env,
+    signing_key: &SigningKey,
+) -> soroban_sdk::BytesN<65> {
+    let pubkey_sec1 = signing_key.verifying_key().to_sec1_bytes();
+    soroban_sdk::BytesN::from_array(env, &<[u8; 65]>::try_from(&pubkey_sec1[..]).unwrap())
+}
+
+/// Upload the smart account and verifier WASM, then deploy the factory with a
+/// freshly generated admin. The funding token is a new Stellar asset issued
+/// by the admin, so tests can mint it. Returns the client and the admin
+/// address.
+pub fn deploy_factory(env: &soroban_sdk::Env) -> (FactoryClient<'_>, soroban_sdk::Address) {
+    use soroban_sdk::testutils::Address as _;
+
+    let account_wasm = env.deployer().upload_contract_wasm(SMART_ACCOUNT_WASM);
+    let verifier_wasm = env.deployer().upload_contract_wasm(WEBAUTHN_VERIFIER_WASM);
+    let admin = soroban_sdk::Address::generate(env);
+
+    let funding_token = env.register_stellar_asset_contract_v2(admin.clone());
+
+    let factory_addr = env.register(
+        FACTORY_WASM,
+        (
+            &admin,
+            &account_wasm,
+            &verifier_wasm,
+            Some(funding_token.address()),
+        ),
+    );
+
+    (FactoryClient::new(env, &factory_addr), admin)
+}
+
+/// Generate a passkey. Returns the signing key and its public key as the
+/// factory expects it.
+#[must_use]
+pub fn random_passkey(env: &soroban_sdk::Env) -> (SigningKey, soroban_sdk::BytesN<65>) {
+    let signing_key = SigningKey::random(&mut p256::elliptic_curve::rand_core::OsRng);
+    let key = passkey_public_key(env, &signing_key);
+    (signing_key, key)
+}
+
+/// Funding-token balance `factory_fixture` mints to its funder.
+pub const FUNDER_BALANCE: i128 = 1_000_000_000;
+
+/// Mock all auths and deploy the factory with `deploy_factory`. Returns the
+/// client and a fresh funder holding `FUNDER_BALANCE` of the funding token.
+pub fn factory_fixture(env: &soroban_sdk::Env) -> (FactoryClient<'_>, soroban_sdk::Address) {
+    use soroban_sdk::testutils::Address as _;
+
+    env.mock_all_auths();
+    let (factory, _admin) = deploy_factory(env);
+    let funder = soroban_sdk::Address::generate(env);
+    soroban_sdk::token::StellarAssetClient::new(env, &factory.funding_token())
+        .mint(&funder, &FUNDER_BALANCE);
+    (factory, funder)
+}
+
+/// Sign the factory's gift claim payload with `claim_key`, sending the gift
+/// to the account of `key` and `credential_id`.
+#[must_use]
+pub fn gift_claim_signature(
+    env: &soroban_sdk::Env,
+    factory: &FactoryClient,
+    claim_key: &ed25519_dalek::SigningKey,
+    key: &soroban_sdk::BytesN<65>,
+    credential_id: &Option<soroban_sdk::Bytes>,
+) -> soroban_sdk::BytesN<64> {
+    use ed25519_dalek::Signer as _;
+
+    let public_key = soroban_sdk::BytesN::from_array(env, &claim_key.verifying_key().to_bytes());
+    let payload = factory.gift_claim_payload(&public_key, key, credential_id);
+    let mut message = std::vec![0; payload.len() as usize];
+    payload.copy_into_slice(&mut message);
+    soroban_sdk::BytesN::from_array(env, &claim_key.sign(&message).to_bytes())
+}
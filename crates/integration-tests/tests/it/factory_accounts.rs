@@ -0,0 +1,161 @@
+use g2c_integration_tests::{
+    deploy_factory, factory_fixture, random_passkey, SmartAccountClient, FUNDER_BALANCE,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env};
+use stellar_accounts::smart_account::Signer;
+
+#[test]
+fn index_zero_matches_legacy_address() {
+    let env = Env::default();
+    let (factory, _admin) = deploy_factory(&env);
+    let funder = Address::generate(&env);
+
+    // Before indices existed, the factory deployed with the funder's address
+    // and an all-zero salt.
+    let legacy = env.as_contract(&factory.address, || {
+        env.deployer()
+            .with_address(funder.clone(), BytesN::from_array(&env, &[0; 32]))
+            .deployed_address()
+    });
+    assert_eq!(factory.get_c_address(&funder, &None), legacy);
+    assert_eq!(factory.get_c_address(&funder, &Some(0)), legacy);
+    assert_ne!(
+        factory.get_c_address(&funder, &Some(0)),
+        factory.get_c_address(&funder, &Some(1))
+    );
+}
+
+#[test]
+fn one_funder_creates_several_accounts() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let (_, personal_key) = random_passkey(&env);
+    let (_, savings_key) = random_passkey(&env);
+
+    let personal = factory.create_account(&funder, &personal_key, &None, &0, &None);
+    let savings = factory.create_account(&funder, &savings_key, &None, &0, &Some(1));
+
+    assert_ne!(personal, savings);
+    assert_eq!(personal, factory.get_c_address(&funder, &None));
+    assert_eq!(savings, factory.get_c_address(&funder, &Some(1)));
+    assert_eq!(factory.get_account_indices(&funder), vec![&env, 0, 1]);
+}
+
+#[test]
+fn key_derived_address_is_independent_of_funder() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (factory, _admin) = deploy_factory(&env);
+
+    let (_, key) = random_passkey(&env);
+    let predicted = factory.predict_address(&key, &None);
+
+    // One party deploys, another tops up later; both land on the same address.
+    let first_funder = Address::generate(&env);
+    let second_funder = Address::generate(&env);
+    assert_eq!(
+        factory.create_account_for_key(&key, &None, &first_funder, &0),
+        predicted
+    );
+    assert_eq!(
+        factory.create_account_for_key(&key, &None, &second_funder, &0),
+        predicted
+    );
+
+    let account = SmartAccountClient::new(&env, &predicted);
+    match account.get_context_rule(&0).signers.get(0).unwrap() {
+        Signer::External(_verifier, key_data) => assert_eq!(key_data, key.to_bytes()),
+        Signer::Delegated(_) => panic!("expected External signer"),
+    }
+}
+
+#[test]
+fn key_derived_address_commits_to_credential_id() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let (_, key) = random_passkey(&env);
+    let credential_id = Some(Bytes::from_array(&env, &[0xAB; 16]));
+    let predicted = factory.predict_address(&key, &credential_id);
+
+    // Deploying the same key with another credential ID lands elsewhere, so
+    // it cannot take the user's address first.
+    let forged = Some(Bytes::from_array(&env, &[0xCD; 16]));
+    let other = factory.create_account_for_key(&key, &forged, &funder, &0);
+    assert_ne!(other, predicted);
+    assert_ne!(
+        factory.predict_address(&key, &None),
+        factory.predict_address(&key, &credential_id)
+    );
+
+    assert_eq!(
+        factory.create_account_for_key(&key, &credential_id, &funder, &0),
+        predicted
+    );
+    assert_eq!(
+        SmartAccountClient::new(&env, &predicted).get_credential_ids(&0),
+        vec![&env, credential_id.unwrap()]
+    );
+}
+
+#[test]
+fn create_account_twice_tops_up_existing_account() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let token = TokenClient::new(&env, &factory.funding_token());
+    let (_, key) = random_passkey(&env);
+
+    let first = factory.create_account(&funder, &key, &None, &300, &None);
+    assert_eq!(token.balance(&first), 300);
+
+    let second = factory.create_account(&funder, &key, &None, &200, &None);
+    assert_eq!(first, second);
+    assert_eq!(token.balance(&second), 500);
+    assert_eq!(token.balance(&funder), FUNDER_BALANCE - 500);
+    assert_eq!(factory.get_account_indices(&funder), vec![&env, 0]);
+}
+
+#[test]
+fn create_account_rejects_other_key_for_existing_account() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let (_, key) = random_passkey(&env);
+    let (_, other_key) = random_passkey(&env);
+
+    factory.create_account(&funder, &key, &None, &0, &None);
+    let result = factory.try_create_account(&funder, &other_key, &None, &0, &None);
+
+    // FactoryError::SignerMismatch
+    assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(1))));
+}
+
+#[test]
+fn create_account_with_assets_moves_every_token() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let usdc = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    let eurc = env.register_stellar_asset_contract_v2(Address::generate(&env));
+    StellarAssetClient::new(&env, &usdc.address()).mint(&funder, &1_000);
+    StellarAssetClient::new(&env, &eurc.address()).mint(&funder, &500);
+
+    let (_, key) = random_passkey(&env);
+    let account = factory.create_account_with_assets(
+        &funder,
+        &key,
+        &None,
+        &vec![&env, (usdc.address(), 600), (eurc.address(), 500)],
+        &None,
+    );
+
+    let usdc_client = TokenClient::new(&env, &usdc.address());
+    let eurc_client = TokenClient::new(&env, &eurc.address());
+    assert_eq!(usdc_client.balance(&account), 600);
+    assert_eq!(usdc_client.balance(&funder), 400);
+    assert_eq!(eurc_client.balance(&account), 500);
+    assert_eq!(eurc_client.balance(&funder), 0);
+}
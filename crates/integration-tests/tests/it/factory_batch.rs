@@ -0,0 +1,124 @@
+use g2c_integration_tests::{factory_fixture, random_passkey};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
+
+/// Mirrors the factory's `MAX_BATCH_SIZE`.
+const MAX_BATCH_SIZE: u32 = 3;
+
+/// Mainnet per-transaction limits, as in
+/// `NetworkInvocationResourceLimits::mainnet`.
+const MAINNET_WRITE_ENTRIES: u32 = 50;
+const MAINNET_INSTRUCTIONS: i64 = 600_000_000;
+
+/// Batch entries whose passkeys all carry a credential ID, the most
+/// expensive case since the registry indexes it too.
+fn entries(env: &Env, count: u32) -> Vec<(BytesN<65>, Option<Bytes>, i128, u32)> {
+    let mut entries = Vec::new(env);
+    for index in 0..count {
+        let (_, key) = random_passkey(env);
+        let credential_id = Bytes::from_array(env, &[u8::try_from(index).unwrap(); 16]);
+        entries.push_back((key, Some(credential_id), 0, index));
+    }
+    entries
+}
+
+/// Write entries and instructions of a `create_accounts` call deploying
+/// the entries `build` returns, after the shared verifier has been deployed.
+fn batch_cost(build: impl Fn(&Env) -> Vec<(BytesN<65>, Option<Bytes>, i128, u32)>) -> (u32, i64) {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let warm_up = Address::generate(&env);
+    factory.create_accounts(&warm_up, &entries(&env, 1));
+
+    factory.create_accounts(&funder, &build(&env));
+    let resources = env.cost_estimate().resources();
+    (resources.write_entries, resources.instructions)
+}
+
+#[test]
+fn batch_deploys_every_entry() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let accounts = factory.create_accounts(&funder, &entries(&env, 3));
+
+    assert_eq!(accounts.len(), 3);
+    for (index, account) in accounts.iter().enumerate() {
+        assert_eq!(
+            account,
+            factory.get_c_address(&funder, &Some(u32::try_from(index).unwrap()))
+        );
+    }
+    assert_eq!(factory.get_account_indices(&funder).len(), 3);
+}
+
+/// A full batch, with a credential ID on every passkey, stays within the
+/// mainnet write-entry and instruction limits of one transaction.
+#[test]
+fn full_batch_fits_mainnet_limits() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    // Deploy the shared verifier first so the batch only pays for accounts.
+    let warm_up = Address::generate(&env);
+    factory.create_accounts(&warm_up, &entries(&env, 1));
+
+    let accounts = factory.create_accounts(&funder, &entries(&env, MAX_BATCH_SIZE));
+    let resources = env.cost_estimate().resources();
+
+    assert_eq!(accounts.len(), MAX_BATCH_SIZE);
+    assert!(resources.write_entries <= MAINNET_WRITE_ENTRIES);
+    assert!(resources.instructions <= MAINNET_INSTRUCTIONS);
+}
+
+/// `MAX_BATCH_SIZE` is the largest batch that fits, given what one more
+/// account adds to a batch on top of the writes and instructions every
+/// batch shares.
+#[test]
+fn max_batch_size_is_derived_from_per_account_cost() {
+    let (one_writes, one_instructions) = batch_cost(|env| entries(env, 1));
+    let (two_writes, two_instructions) = batch_cost(|env| entries(env, 2));
+    let account_writes = two_writes - one_writes;
+    let account_instructions = two_instructions - one_instructions;
+    let shared_writes = one_writes - account_writes;
+    let shared_instructions = one_instructions - account_instructions;
+
+    let fits = |size: u32| {
+        shared_writes + size * account_writes <= MAINNET_WRITE_ENTRIES
+            && shared_instructions + i64::from(size) * account_instructions <= MAINNET_INSTRUCTIONS
+    };
+    assert!(fits(MAX_BATCH_SIZE));
+    assert!(!fits(MAX_BATCH_SIZE + 1));
+    // Writes are the binding limit: one more account would still fit in the
+    // instruction limit.
+    assert!(
+        shared_instructions + i64::from(MAX_BATCH_SIZE + 1) * account_instructions
+            <= MAINNET_INSTRUCTIONS
+    );
+}
+
+/// Indexing a credential ID costs the registry three more writes per
+/// account: the entry's length, the account's slot and its position.
+#[test]
+fn credential_id_adds_three_registry_writes() {
+    let (with_ids, _) = batch_cost(|env| entries(env, 2));
+    let (without_ids, _) = batch_cost(|env| {
+        Vec::from_iter(
+            env,
+            entries(env, 2)
+                .iter()
+                .map(|(key, _, amount, index)| (key, None, amount, index)),
+        )
+    });
+
+    assert_eq!(with_ids - without_ids, 2 * 3);
+}
+
+#[test]
+fn oversized_batch_is_rejected() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let result = factory.try_create_accounts(&funder, &entries(&env, MAX_BATCH_SIZE + 1));
+
+    assert_eq!(result, Err(Ok(soroban_sdk::Error::from_contract_error(8))));
+}
@@ -0,0 +1,140 @@
+use g2c_integration_tests::{
+    factory_fixture, random_passkey, AccountConfig, ContextRuleSpec, SignerSpec,
+    SmartAccountClient, ED25519_VERIFIER_WASM,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, vec, Address, BytesN, Env, Map, String, Vec};
+use stellar_accounts::smart_account::{ContextRuleType, Signer};
+
+fn single_signer(env: &Env, signer: SignerSpec) -> AccountConfig {
+    AccountConfig {
+        signers: vec![env, signer],
+        policies: Map::new(env),
+        context_rules: Vec::new(env),
+    }
+}
+
+#[test]
+fn create_account_with_config_installs_signers_and_rules() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let recovery = Address::generate(&env);
+    let dapp = Address::generate(&env);
+
+    let (_, phone) = random_passkey(&env);
+    let (_, session) = random_passkey(&env);
+    let config = AccountConfig {
+        signers: vec![
+            &env,
+            SignerSpec::WebAuthn(phone.clone(), None),
+            SignerSpec::Delegated(recovery.clone()),
+        ],
+        policies: Map::new(&env),
+        context_rules: vec![
+            &env,
+            ContextRuleSpec {
+                context_type: ContextRuleType::CallContract(dapp.clone()),
+                name: String::from_str(&env, "dapp"),
+                valid_until: Some(1_000),
+                signers: vec![&env, SignerSpec::WebAuthn(session.clone(), None)],
+                policies: Map::new(&env),
+            },
+        ],
+    };
+
+    let account = factory.create_account_with_config(&funder, &config, &0, &None);
+    let client = SmartAccountClient::new(&env, &account);
+
+    assert_eq!(client.get_context_rules_count(), 2);
+    let default_signers = client.get_context_rule(&0).signers;
+    let Signer::External(verifier, key_data) = default_signers.get_unchecked(0) else {
+        panic!("passkey should be an External signer");
+    };
+    assert_eq!(key_data, phone.to_bytes());
+    assert_eq!(
+        default_signers.get_unchecked(1),
+        Signer::Delegated(recovery)
+    );
+
+    let dapp_rule = client.get_context_rule(&1);
+    assert!(matches!(dapp_rule.context_type, ContextRuleType::CallContract(a) if a == dapp));
+    assert_eq!(dapp_rule.valid_until, Some(1_000));
+    assert_eq!(
+        dapp_rule.signers,
+        vec![&env, Signer::External(verifier, session.to_bytes())]
+    );
+    assert_eq!(factory.lookup_by_key(&phone, &0, &10), vec![&env, account]);
+}
+
+#[test]
+fn create_account_with_config_rejects_empty_signers() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let config = AccountConfig {
+        signers: Vec::new(&env),
+        policies: Map::new(&env),
+        context_rules: Vec::new(&env),
+    };
+
+    // FactoryError::NoSigners
+    assert_eq!(
+        factory.try_create_account_with_config(&funder, &config, &0, &None),
+        Err(Ok(soroban_sdk::Error::from_contract_error(3)))
+    );
+}
+
+#[test]
+fn unregistered_verifier_kinds_are_rejected() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let ed25519 = single_signer(
+        &env,
+        SignerSpec::Ed25519(BytesN::from_array(&env, &[1; 32])),
+    );
+    let secp256k1 = single_signer(
+        &env,
+        SignerSpec::External(
+            symbol_short!("secp256k1"),
+            random_passkey(&env).1.to_bytes(),
+        ),
+    );
+
+    // FactoryError::UnknownVerifier
+    let unknown = Err(Ok(soroban_sdk::Error::from_contract_error(12)));
+    assert_eq!(
+        factory.try_create_account_with_config(&funder, &ed25519, &0, &None),
+        unknown
+    );
+    assert_eq!(
+        factory.try_create_account_with_config(&funder, &secp256k1, &0, &Some(1)),
+        unknown
+    );
+}
+
+#[test]
+fn registered_verifier_kind_backs_ed25519_signers() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let wasm = env.deployer().upload_contract_wasm(ED25519_VERIFIER_WASM);
+    let kind = symbol_short!("ed25519");
+
+    factory.register_verifier(&kind, &wasm);
+    assert_eq!(factory.get_verifier_kind_wasm(&kind), Some(wasm));
+
+    let key = BytesN::from_array(&env, &[1; 32]);
+    let config = single_signer(&env, SignerSpec::Ed25519(key.clone()));
+    let account = factory.create_account_with_config(&funder, &config, &0, &None);
+
+    let signers = SmartAccountClient::new(&env, &account)
+        .get_context_rule(&0)
+        .signers;
+    assert!(matches!(
+        signers.get_unchecked(0),
+        Signer::External(_, key_data) if key_data == key.to_bytes()
+    ));
+
+    factory.remove_verifier(&kind);
+    assert_eq!(factory.get_verifier_kind_wasm(&kind), None);
+}
@@ -0,0 +1,175 @@
+use g2c_integration_tests::{
+    factory_fixture, gift_claim_signature, random_passkey, AccountConfig, AccountCreated,
+    FactoryClient, SignerSpec, SmartAccountClient,
+};
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::{vec, xdr, Address, BytesN, Env, Event as _, Map, Vec};
+use stellar_accounts::smart_account::Signer;
+
+/// The first signer the factory put on the account's default rule.
+fn first_signer(env: &Env, account: &Address) -> Signer {
+    SmartAccountClient::new(env, account)
+        .get_context_rule(&0)
+        .signers
+        .get(0)
+        .unwrap()
+}
+
+fn expected_event(
+    env: &Env,
+    factory: &FactoryClient,
+    funder: &Address,
+    account: &Address,
+    amount: i128,
+) -> AccountCreated {
+    AccountCreated {
+        funder: funder.clone(),
+        account: account.clone(),
+        signer: first_signer(env, account),
+        amount,
+        account_wasm: factory.get_account_wasm(),
+    }
+}
+
+/// The `AccountCreated` events among the factory's events from the last
+/// invocation.
+fn account_created(env: &Env, factory: &FactoryClient) -> std::vec::Vec<xdr::ContractEvent> {
+    let topic = xdr::ScVal::Symbol(xdr::ScSymbol("account_created".try_into().unwrap()));
+    env.events()
+        .all()
+        .filter_by_contract(&factory.address)
+        .events()
+        .iter()
+        .filter(|event| match &event.body {
+            xdr::ContractEventBody::V0(body) => body.topics.first() == Some(&topic),
+        })
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn create_account_emits_account_created() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let (_, key) = random_passkey(&env);
+    let account = factory.create_account(&funder, &key, &None, &100, &None);
+    let events = env.events().all().filter_by_contract(&factory.address);
+
+    let expected = expected_event(&env, &factory, &funder, &account, 100);
+    assert_eq!(events, [expected.to_xdr(&env, &factory.address)]);
+    assert!(
+        matches!(&expected.signer, Signer::External(_, key_data) if *key_data == key.to_bytes())
+    );
+}
+
+#[test]
+fn create_account_for_key_emits_account_created() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let (_, key) = random_passkey(&env);
+    let account = factory.create_account_for_key(&key, &None, &funder, &0);
+    let events = env.events().all().filter_by_contract(&factory.address);
+
+    let expected = expected_event(&env, &factory, &funder, &account, 0);
+    assert_eq!(events, [expected.to_xdr(&env, &factory.address)]);
+}
+
+#[test]
+fn sponsored_creation_emits_account_created() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let relayer = Address::generate(&env);
+
+    let (_, key) = random_passkey(&env);
+    let account = factory.create_account_sponsored(&relayer, &funder, &key, &None, &50);
+    let events = env.events().all().filter_by_contract(&factory.address);
+
+    let expected = expected_event(&env, &factory, &funder, &account, 50);
+    assert_eq!(events, [expected.to_xdr(&env, &factory.address)]);
+}
+
+/// A configured account whose first signer is a delegated address reports
+/// that signer as is, rather than an empty key.
+#[test]
+fn create_account_with_config_reports_delegated_signer() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let recovery = Address::generate(&env);
+
+    let (_, key) = random_passkey(&env);
+    let config = AccountConfig {
+        signers: vec![
+            &env,
+            SignerSpec::Delegated(recovery.clone()),
+            SignerSpec::WebAuthn(key, None),
+        ],
+        policies: Map::new(&env),
+        context_rules: Vec::new(&env),
+    };
+    let account = factory.create_account_with_config(&funder, &config, &0, &None);
+    let events = env.events().all().filter_by_contract(&factory.address);
+
+    let expected = expected_event(&env, &factory, &funder, &account, 0);
+    assert_eq!(expected.signer, Signer::Delegated(recovery));
+    assert_eq!(events, [expected.to_xdr(&env, &factory.address)]);
+}
+
+#[test]
+fn create_accounts_emits_one_event_per_deployment() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let mut entries = Vec::new(&env);
+    for index in 0..3 {
+        let (_, key) = random_passkey(&env);
+        entries.push_back((key, None, 10, index));
+    }
+    let accounts = factory.create_accounts(&funder, &entries);
+    let events = env.events().all().filter_by_contract(&factory.address);
+
+    let expected: std::vec::Vec<_> = accounts
+        .iter()
+        .map(|account| {
+            expected_event(&env, &factory, &funder, &account, 10).to_xdr(&env, &factory.address)
+        })
+        .collect();
+    assert_eq!(events.events(), expected.as_slice());
+}
+
+/// A claimed gift is deployed on the sender's behalf, so the sender is
+/// reported as the funder.
+#[test]
+fn claim_gift_emits_account_created() {
+    let env = Env::default();
+    let (factory, sender) = factory_fixture(&env);
+    let token = factory.funding_token();
+
+    let claim_secret =
+        ed25519_dalek::SigningKey::generate(&mut p256::elliptic_curve::rand_core::OsRng);
+    let claim_key = BytesN::from_array(&env, &claim_secret.verifying_key().to_bytes());
+    factory.deposit_gift(&sender, &token, &700, &claim_key, &100);
+
+    let (_, key) = random_passkey(&env);
+    let signature = gift_claim_signature(&env, &factory, &claim_secret, &key, &None);
+    let account = factory.claim_gift(&claim_key, &key, &None, &signature);
+
+    let expected = expected_event(&env, &factory, &sender, &account, 700);
+    assert_eq!(
+        account_created(&env, &factory),
+        [expected.to_xdr(&env, &factory.address)]
+    );
+}
+
+#[test]
+fn top_up_does_not_emit_account_created() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let (_, key) = random_passkey(&env);
+    factory.create_account(&funder, &key, &None, &0, &None);
+    factory.create_account(&funder, &key, &None, &0, &None);
+
+    assert!(account_created(&env, &factory).is_empty());
+}
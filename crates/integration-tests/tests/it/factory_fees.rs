@@ -0,0 +1,226 @@
+use ed25519_dalek::SigningKey;
+use g2c_integration_tests::{
+    deploy_factory, factory_fixture, gift_claim_signature, random_passkey, FeeConfig,
+    FUNDER_BALANCE,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+#[test]
+fn fee_is_disabled_by_default() {
+    let env = Env::default();
+    let (factory, _admin) = deploy_factory(&env);
+
+    assert_eq!(factory.get_fee(), FeeConfig::Disabled);
+    assert_eq!(factory.get_treasury(), None);
+}
+
+#[test]
+fn admin_sets_fee_and_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (factory, _admin) = deploy_factory(&env);
+    let treasury = Address::generate(&env);
+
+    factory.set_fee(&FeeConfig::Bps(50), &treasury);
+
+    assert_eq!(factory.get_fee(), FeeConfig::Bps(50));
+    assert_eq!(factory.get_treasury(), Some(treasury));
+}
+
+#[test]
+fn rejects_invalid_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (factory, _admin) = deploy_factory(&env);
+    let treasury = Address::generate(&env);
+
+    let invalid = Err(Ok(soroban_sdk::Error::from_contract_error(9)));
+    assert_eq!(
+        factory.try_set_fee(&FeeConfig::Bps(10_001), &treasury),
+        invalid
+    );
+    assert_eq!(
+        factory.try_set_fee(&FeeConfig::Flat(-1), &treasury),
+        invalid
+    );
+}
+
+#[test]
+fn fee_settings_require_admin_auth() {
+    let env = Env::default();
+    let (factory, _admin) = deploy_factory(&env);
+    let treasury = Address::generate(&env);
+
+    assert!(factory.try_set_fee(&FeeConfig::Flat(1), &treasury).is_err());
+    assert!(factory.try_set_fee_exempt(&treasury, &true).is_err());
+}
+
+/// The funder holds no funding token, so creation only succeeds when no fee
+/// is due.
+#[test]
+fn exempt_funders_skip_the_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (factory, _admin) = deploy_factory(&env);
+    let treasury = Address::generate(&env);
+    let funder = Address::generate(&env);
+    factory.set_fee(&FeeConfig::Flat(1_000_000), &treasury);
+
+    let (_, key) = random_passkey(&env);
+    assert!(factory
+        .try_create_account(&funder, &key, &None, &0, &None)
+        .is_err());
+
+    factory.set_fee_exempt(&funder, &true);
+    assert!(factory.is_fee_exempt(&funder));
+    factory.create_account(&funder, &key, &None, &0, &None);
+
+    factory.set_fee_exempt(&funder, &false);
+    assert!(!factory.is_fee_exempt(&funder));
+    assert!(factory
+        .try_create_account(&funder, &key, &None, &0, &Some(1))
+        .is_err());
+}
+
+#[test]
+fn bps_fee_is_paid_to_treasury_on_top_of_amount() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let token = TokenClient::new(&env, &factory.funding_token());
+    let treasury = Address::generate(&env);
+    factory.set_fee(&FeeConfig::Bps(100), &treasury);
+
+    let (_, key) = random_passkey(&env);
+    let account = factory.create_account(&funder, &key, &None, &5_000, &None);
+
+    assert_eq!(token.balance(&account), 5_000);
+    assert_eq!(token.balance(&treasury), 50);
+    assert_eq!(token.balance(&funder), FUNDER_BALANCE - 5_050);
+}
+
+#[test]
+fn flat_fee_is_charged_per_deployment_not_on_top_ups() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let token = TokenClient::new(&env, &factory.funding_token());
+    let treasury = Address::generate(&env);
+    factory.set_fee(&FeeConfig::Flat(100), &treasury);
+
+    let (_, key) = random_passkey(&env);
+    let account = factory.create_account(&funder, &key, &None, &1_000, &None);
+    factory.create_account(&funder, &key, &None, &1_000, &None);
+
+    assert_eq!(token.balance(&account), 2_000);
+    assert_eq!(token.balance(&treasury), 100);
+    assert_eq!(token.balance(&funder), FUNDER_BALANCE - 2_100);
+}
+
+#[test]
+fn batch_charges_only_for_deployed_entries() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let token = TokenClient::new(&env, &factory.funding_token());
+    let treasury = Address::generate(&env);
+    factory.set_fee(&FeeConfig::Flat(100), &treasury);
+    let (_, existing) = random_passkey(&env);
+    factory.create_account(&funder, &existing, &None, &0, &None);
+
+    let mut entries = Vec::new(&env);
+    entries.push_back((existing, None, 1_000, 0));
+    for index in 1..3 {
+        let (_, key) = random_passkey(&env);
+        entries.push_back((key, None, 1_000, index));
+    }
+    factory.create_accounts(&funder, &entries);
+
+    assert_eq!(token.balance(&treasury), 300);
+    assert_eq!(token.balance(&funder), FUNDER_BALANCE - 3_300);
+}
+
+#[test]
+fn key_accounts_are_charged_once() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let token = TokenClient::new(&env, &factory.funding_token());
+    let treasury = Address::generate(&env);
+    factory.set_fee(&FeeConfig::Flat(100), &treasury);
+
+    let (_, key) = random_passkey(&env);
+    let account = factory.create_account_for_key(&key, &None, &funder, &1_000);
+    factory.create_account_for_key(&key, &None, &funder, &1_000);
+
+    assert_eq!(token.balance(&account), 2_000);
+    assert_eq!(token.balance(&treasury), 100);
+    assert_eq!(token.balance(&funder), FUNDER_BALANCE - 2_100);
+}
+
+#[test]
+fn sponsored_accounts_are_charged_once() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let token = TokenClient::new(&env, &factory.funding_token());
+    let treasury = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    factory.set_fee(&FeeConfig::Flat(100), &treasury);
+
+    let (_, key) = random_passkey(&env);
+    let account = factory.create_account_sponsored(&relayer, &funder, &key, &None, &1_000);
+    factory.create_account_sponsored(&relayer, &funder, &key, &None, &1_000);
+
+    assert_eq!(token.balance(&account), 2_000);
+    assert_eq!(token.balance(&treasury), 100);
+    assert_eq!(token.balance(&funder), FUNDER_BALANCE - 2_100);
+}
+
+/// Claiming a gift may deploy an account, so the sender escrows the fee with
+/// the gift. It goes to the treasury when the claim deploys the account and
+/// back to the sender when the account already exists.
+#[test]
+fn gift_senders_pay_the_fee_for_deployed_accounts() {
+    let env = Env::default();
+    let (factory, sender) = factory_fixture(&env);
+    let token = TokenClient::new(&env, &factory.funding_token());
+    let treasury = Address::generate(&env);
+    factory.set_fee(&FeeConfig::Flat(100), &treasury);
+    let (_, key) = random_passkey(&env);
+
+    let claim = || {
+        let claim_secret = SigningKey::generate(&mut p256::elliptic_curve::rand_core::OsRng);
+        let claim_key = BytesN::from_array(&env, &claim_secret.verifying_key().to_bytes());
+        factory.deposit_gift(&sender, &token.address, &1_000, &claim_key, &100);
+        let signature = gift_claim_signature(&env, &factory, &claim_secret, &key, &None);
+        factory.claim_gift(&claim_key, &key, &None, &signature)
+    };
+
+    let account = claim();
+    assert_eq!(token.balance(&treasury), 100);
+    assert_eq!(token.balance(&sender), FUNDER_BALANCE - 1_100);
+
+    claim();
+    assert_eq!(token.balance(&account), 2_000);
+    assert_eq!(token.balance(&treasury), 100);
+    assert_eq!(token.balance(&sender), FUNDER_BALANCE - 2_100);
+    assert_eq!(token.balance(&factory.address), 0);
+}
+
+/// An empty gift would let a claim deploy an account for nothing.
+#[test]
+fn gifts_must_have_a_positive_amount() {
+    let env = Env::default();
+    let (factory, sender) = factory_fixture(&env);
+    let token = factory.funding_token();
+    let claim_key = BytesN::from_array(&env, &[7; 32]);
+
+    // InvalidAmount
+    let invalid = Err(Ok(soroban_sdk::Error::from_contract_error(16)));
+    assert_eq!(
+        factory.try_deposit_gift(&sender, &token, &0, &claim_key, &100),
+        invalid
+    );
+    assert_eq!(
+        factory.try_deposit_gift(&sender, &token, &-1, &claim_key, &100),
+        invalid
+    );
+}
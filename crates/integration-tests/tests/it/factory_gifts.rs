@@ -0,0 +1,134 @@
+use ed25519_dalek::SigningKey;
+use g2c_integration_tests::{
+    deploy_factory, gift_claim_signature, random_passkey, FactoryClient, Gift,
+};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+const EXPIRY: u32 = 100;
+
+/// Deposit a 1 000-unit gift from a fresh sender under a new claim keypair.
+/// Returns the token, the sender, the claim signing key and its public key.
+fn deposit(env: &Env, factory: &FactoryClient) -> (Address, Address, SigningKey, BytesN<32>) {
+    let sender = Address::generate(env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(env))
+        .address();
+    StellarAssetClient::new(env, &token).mint(&sender, &1_000);
+
+    let claim_secret = SigningKey::generate(&mut p256::elliptic_curve::rand_core::OsRng);
+    let claim_key = BytesN::from_array(env, &claim_secret.verifying_key().to_bytes());
+    factory.deposit_gift(&sender, &token, &1_000, &claim_key, &EXPIRY);
+    (token, sender, claim_secret, claim_key)
+}
+
+#[test]
+fn claim_deploys_account_and_releases_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (factory, _admin) = deploy_factory(&env);
+    let (token, sender, claim_secret, claim_key) = deposit(&env, &factory);
+    let balances = TokenClient::new(&env, &token);
+
+    assert_eq!(
+        factory.get_gift(&claim_key),
+        Some(Gift {
+            sender: sender.clone(),
+            token: token.clone(),
+            amount: 1_000,
+            fee: 0,
+            expiry_ledger: EXPIRY,
+        })
+    );
+    assert_eq!(balances.balance(&factory.address), 1_000);
+
+    let (_, key) = random_passkey(&env);
+    let credential_id = Some(Bytes::from_array(&env, &[0xAB; 16]));
+    let signature = gift_claim_signature(&env, &factory, &claim_secret, &key, &credential_id);
+    let account = factory.claim_gift(&claim_key, &key, &credential_id, &signature);
+
+    assert_eq!(account, factory.predict_address(&key, &credential_id));
+    assert_eq!(balances.balance(&account), 1_000);
+    assert_eq!(balances.balance(&factory.address), 0);
+    assert_eq!(balances.balance(&sender), 0);
+    assert_eq!(factory.get_gift(&claim_key), None);
+    // The escrow is closed, so the claim cannot be replayed.
+    assert_eq!(
+        factory.try_claim_gift(&claim_key, &key, &credential_id, &signature),
+        Err(Ok(soroban_sdk::Error::from_contract_error(5)))
+    );
+}
+
+/// A front-runner who copies a pending claim cannot point it at their own
+/// passkey or credential ID: the signature only covers the claimant's.
+#[test]
+fn observed_claim_cannot_be_redirected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (factory, _admin) = deploy_factory(&env);
+    let (token, _sender, claim_secret, claim_key) = deposit(&env, &factory);
+
+    let (_, key) = random_passkey(&env);
+    let signature = gift_claim_signature(&env, &factory, &claim_secret, &key, &None);
+
+    let (_, attacker_key) = random_passkey(&env);
+    assert!(factory
+        .try_claim_gift(&claim_key, &attacker_key, &None, &signature)
+        .is_err());
+    let forged_credential = Some(Bytes::from_array(&env, &[0xCD; 16]));
+    assert!(factory
+        .try_claim_gift(&claim_key, &key, &forged_credential, &signature)
+        .is_err());
+
+    // The gift is still there for the intended account.
+    let account = factory.claim_gift(&claim_key, &key, &None, &signature);
+    assert_eq!(TokenClient::new(&env, &token).balance(&account), 1_000);
+}
+
+#[test]
+fn other_claim_keys_cannot_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (factory, _admin) = deploy_factory(&env);
+    let (_token, _sender, _claim_secret, claim_key) = deposit(&env, &factory);
+
+    let (_, key) = random_passkey(&env);
+    let guess = SigningKey::generate(&mut p256::elliptic_curve::rand_core::OsRng);
+    let signature = gift_claim_signature(&env, &factory, &guess, &key, &None);
+    assert!(factory
+        .try_claim_gift(&claim_key, &key, &None, &signature)
+        .is_err());
+
+    // GiftNotFound: nothing is held under the guessed key.
+    let guess_key = BytesN::from_array(&env, &guess.verifying_key().to_bytes());
+    assert_eq!(
+        factory.try_claim_gift(&guess_key, &key, &None, &signature),
+        Err(Ok(soroban_sdk::Error::from_contract_error(5)))
+    );
+}
+
+#[test]
+fn refund_only_after_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (factory, _admin) = deploy_factory(&env);
+    let (token, sender, claim_secret, claim_key) = deposit(&env, &factory);
+
+    assert_eq!(
+        factory.try_refund_gift(&claim_key),
+        Err(Ok(soroban_sdk::Error::from_contract_error(7)))
+    );
+
+    env.ledger().set_sequence_number(EXPIRY + 1);
+    let (_, key) = random_passkey(&env);
+    let signature = gift_claim_signature(&env, &factory, &claim_secret, &key, &None);
+    assert_eq!(
+        factory.try_claim_gift(&claim_key, &key, &None, &signature),
+        Err(Ok(soroban_sdk::Error::from_contract_error(6)))
+    );
+
+    factory.refund_gift(&claim_key);
+    assert_eq!(TokenClient::new(&env, &token).balance(&sender), 1_000);
+    assert_eq!(factory.get_gift(&claim_key), None);
+}
@@ -0,0 +1,134 @@
+use ed25519_dalek::SigningKey;
+use g2c_integration_tests::{
+    deploy_factory, factory_fixture, gift_claim_signature, random_passkey, AccountConfig,
+    SignerSpec,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{symbol_short, vec, Address, BytesN, Env, Map, Vec};
+
+#[test]
+fn guardian_pause_blocks_deployments() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let guardian = Address::generate(&env);
+    factory.set_guardian(&Some(guardian.clone()));
+
+    factory.pause(&guardian);
+    assert!(factory.is_paused());
+
+    let (_, key) = random_passkey(&env);
+    let paused = Err(Ok(soroban_sdk::Error::from_contract_error(11)));
+    assert_eq!(
+        factory.try_create_account(&funder, &key, &None, &0, &None),
+        paused
+    );
+    assert_eq!(
+        factory.try_create_account_for_key(&key, &None, &funder, &0),
+        paused
+    );
+
+    // Address derivation is unaffected.
+    let expected = factory.get_c_address(&funder, &None);
+
+    factory.unpause();
+    assert!(!factory.is_paused());
+    assert_eq!(
+        factory.create_account(&funder, &key, &None, &0, &None),
+        expected
+    );
+}
+
+/// Every creation entrypoint fails while paused, including top-ups of an
+/// existing account, while gift deposits still go through.
+#[test]
+fn pause_blocks_every_creation_entrypoint() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let relayer = Address::generate(&env);
+    let (_, key) = random_passkey(&env);
+    factory.create_account(&funder, &key, &None, &0, &None);
+    factory.pause(&factory.get_admin());
+
+    let token = factory.funding_token();
+    let claim_secret = SigningKey::generate(&mut p256::elliptic_curve::rand_core::OsRng);
+    let claim_key = BytesN::from_array(&env, &claim_secret.verifying_key().to_bytes());
+    factory.deposit_gift(&funder, &token, &100, &claim_key, &100);
+    let signature = gift_claim_signature(&env, &factory, &claim_secret, &key, &None);
+    let config = AccountConfig {
+        signers: vec![&env, SignerSpec::WebAuthn(key.clone(), None)],
+        policies: Map::new(&env),
+        context_rules: Vec::new(&env),
+    };
+
+    let paused = Err(Ok(soroban_sdk::Error::from_contract_error(11)));
+    assert_eq!(
+        factory.try_create_account(&funder, &key, &None, &10, &None),
+        paused
+    );
+    assert_eq!(
+        factory.try_create_account_with_assets(
+            &funder,
+            &key,
+            &None,
+            &vec![&env, (token.clone(), 10)],
+            &None
+        ),
+        paused
+    );
+    assert_eq!(
+        factory.try_create_account_with_config(&funder, &config, &10, &Some(1)),
+        paused
+    );
+    assert_eq!(
+        factory.try_create_accounts(&funder, &vec![&env, (key.clone(), None, 10, 0)]),
+        paused
+    );
+    assert_eq!(
+        factory.try_create_account_for_key(&key, &None, &funder, &10),
+        paused
+    );
+    assert_eq!(
+        factory.try_create_account_sponsored(&relayer, &funder, &key, &None, &10),
+        paused
+    );
+    assert_eq!(
+        factory.try_claim_gift(&claim_key, &key, &None, &signature),
+        paused
+    );
+    assert_eq!(
+        factory.try_ensure_verifier(&symbol_short!("webauthn")),
+        paused
+    );
+}
+
+#[test]
+fn only_admin_or_guardian_can_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (factory, admin) = deploy_factory(&env);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        factory.try_pause(&stranger),
+        Err(Ok(soroban_sdk::Error::from_contract_error(10)))
+    );
+    assert!(!factory.is_paused());
+
+    factory.pause(&admin);
+    assert!(factory.is_paused());
+}
+
+#[test]
+fn admin_clears_guardian() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (factory, _admin) = deploy_factory(&env);
+    let guardian = Address::generate(&env);
+
+    factory.set_guardian(&Some(guardian.clone()));
+    assert_eq!(factory.get_guardian(), Some(guardian.clone()));
+
+    factory.set_guardian(&None);
+    assert_eq!(factory.get_guardian(), None);
+    assert!(factory.try_pause(&guardian).is_err());
+}
@@ -0,0 +1,138 @@
+use g2c_integration_tests::{deploy_factory, factory_fixture, random_passkey, SmartAccountClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Bytes, Env};
+use stellar_accounts::smart_account::Signer;
+
+/// The WebAuthn verifier of the account's first signer.
+fn verifier_of(client: &SmartAccountClient) -> Address {
+    match client.get_context_rule(&0).signers.get(0).unwrap() {
+        Signer::External(verifier, _) => verifier,
+        Signer::Delegated(_) => panic!("expected External signer"),
+    }
+}
+
+#[test]
+fn create_account_indexes_passkey() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let (_, key) = random_passkey(&env);
+    let personal = factory.create_account(&funder, &key, &None, &0, &None);
+    let savings = factory.create_account(&funder, &key, &None, &0, &Some(1));
+
+    assert_eq!(
+        factory.lookup_by_key(&key, &0, &10),
+        vec![&env, personal, savings]
+    );
+
+    let (_, unknown) = random_passkey(&env);
+    assert!(factory.lookup_by_key(&unknown, &0, &10).is_empty());
+}
+
+#[test]
+fn account_hook_registers_and_unregisters_signer() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let (_, key) = random_passkey(&env);
+    let account = factory.create_account(&funder, &key, &None, &0, &None);
+    let client = SmartAccountClient::new(&env, &account);
+
+    // A backup passkey added to the account later, with its credential ID.
+    let (_, backup_pubkey) = random_passkey(&env);
+    let credential_id = Bytes::from_array(&env, &[0xAB; 16]);
+    let mut key_data = backup_pubkey.to_bytes();
+    key_data.append(&credential_id);
+    let backup = Signer::External(verifier_of(&client), key_data.clone());
+
+    // SignerNotFound: the passkey is not on the account yet.
+    assert_eq!(
+        factory.try_register_signer(&account, &key_data).err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(14)))
+    );
+
+    client.add_signer(&0, &backup);
+    factory.register_signer(&account, &key_data);
+    assert_eq!(
+        factory.lookup_by_key(&backup_pubkey, &0, &10),
+        vec![&env, account.clone()]
+    );
+    assert_eq!(
+        factory.lookup_by_credential(&credential_id, &0, &10),
+        vec![&env, account.clone()]
+    );
+
+    // SignerStillActive: the passkey has to be removed from the account first.
+    assert_eq!(
+        factory.try_unregister_signer(&account, &key_data).err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(15)))
+    );
+
+    client.remove_signer(&0, &backup);
+    factory.unregister_signer(&account, &key_data);
+    assert!(factory.lookup_by_key(&backup_pubkey, &0, &10).is_empty());
+    assert!(factory
+        .lookup_by_credential(&credential_id, &0, &10)
+        .is_empty());
+}
+
+#[test]
+fn credential_id_is_stored_with_the_passkey() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+
+    let (_, key) = random_passkey(&env);
+    let credential_id = Bytes::from_array(&env, &[0xCD; 20]);
+    let account = factory.create_account(&funder, &key, &Some(credential_id.clone()), &0, &None);
+
+    let mut key_data = key.to_bytes();
+    key_data.append(&credential_id);
+    let client = SmartAccountClient::new(&env, &account);
+    match client.get_context_rule(&0).signers.get(0).unwrap() {
+        Signer::External(_verifier, stored) => assert_eq!(stored, key_data),
+        Signer::Delegated(_) => panic!("expected External signer"),
+    }
+    assert_eq!(
+        client.get_credential_ids(&0),
+        vec![&env, credential_id.clone()]
+    );
+    assert_eq!(
+        factory.lookup_by_credential(&credential_id, &0, &10),
+        vec![&env, account]
+    );
+}
+
+#[test]
+fn lookups_are_paginated() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let (_, key) = random_passkey(&env);
+
+    // One funder can create any number of accounts with the same passkey, so
+    // an entry can hold more accounts than one page.
+    let accounts: std::vec::Vec<Address> = (0..60)
+        .map(|index| factory.create_account(&funder, &key, &None, &0, &Some(index)))
+        .collect();
+
+    // Pages are capped at 50 accounts.
+    let first = factory.lookup_by_key(&key, &0, &100);
+    assert_eq!(first.len(), 50);
+    assert_eq!(first.get(0), Some(accounts[0].clone()));
+    let second = factory.lookup_by_key(&key, &50, &50);
+    assert_eq!(second.len(), 10);
+    assert_eq!(second.get(9), Some(accounts[59].clone()));
+    assert!(factory.lookup_by_key(&key, &60, &50).is_empty());
+
+    // Replace the passkey on one account, then drop it from the index. The
+    // last account moves into its slot.
+    let client = SmartAccountClient::new(&env, &accounts[3]);
+    let verifier = verifier_of(&client);
+    let (_, new_key) = random_passkey(&env);
+    client.add_signer(&0, &Signer::External(verifier.clone(), new_key.to_bytes()));
+    client.remove_signer(&0, &Signer::External(verifier, key.to_bytes()));
+    factory.unregister_signer(&accounts[3], &key.to_bytes());
+
+    let page = factory.lookup_by_key(&key, &0, &5);
+    assert_eq!(page.get(3), Some(accounts[59].clone()));
+    assert_eq!(factory.lookup_by_key(&key, &50, &50).len(), 9);
+}
@@ -0,0 +1,85 @@
+use g2c_integration_tests::{
+    deploy_factory, factory_fixture, random_passkey, FactoryClient, FACTORY_WASM, FUNDER_BALANCE,
+    SMART_ACCOUNT_WASM, WEBAUTHN_VERIFIER_WASM,
+};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, BytesN, Env};
+
+#[test]
+fn constructor_records_initial_wasm_versions() {
+    let env = Env::default();
+    let (factory, _admin) = deploy_factory(&env);
+
+    let account_wasm = env.deployer().upload_contract_wasm(SMART_ACCOUNT_WASM);
+    let verifier_wasm = env.deployer().upload_contract_wasm(WEBAUTHN_VERIFIER_WASM);
+
+    assert_eq!(factory.get_account_wasm(), account_wasm);
+    assert_eq!(factory.get_account_wasm_version(), 1);
+    assert_eq!(factory.get_account_wasm_at(&1), Some(account_wasm));
+    assert_eq!(factory.get_verifier_wasm(), verifier_wasm);
+    assert_eq!(factory.get_verifier_wasm_version(), 1);
+    assert_eq!(factory.get_verifier_wasm_at(&1), Some(verifier_wasm));
+}
+
+#[test]
+fn admin_can_set_account_wasm_and_history_is_kept() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (factory, _admin) = deploy_factory(&env);
+
+    let original = factory.get_account_wasm();
+    let next = BytesN::from_array(&env, &[7; 32]);
+
+    assert_eq!(factory.set_account_wasm(&next), 2);
+    assert_eq!(factory.get_account_wasm(), next);
+    assert_eq!(factory.get_account_wasm_version(), 2);
+    assert_eq!(factory.get_account_wasm_at(&1), Some(original));
+    assert_eq!(factory.get_account_wasm_at(&2), Some(next));
+    assert_eq!(factory.get_account_wasm_at(&3), None);
+}
+
+#[test]
+fn set_account_wasm_requires_admin_auth() {
+    let env = Env::default();
+    let (factory, _admin) = deploy_factory(&env);
+
+    let next = BytesN::from_array(&env, &[7; 32]);
+    assert!(factory.try_set_account_wasm(&next).is_err());
+    assert_eq!(factory.get_account_wasm_version(), 1);
+}
+
+#[test]
+fn funding_token_comes_from_constructor() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let token = TokenClient::new(&env, &factory.funding_token());
+
+    let (_, key) = random_passkey(&env);
+    let account = factory.create_account(&funder, &key, &None, &400, &None);
+
+    assert_eq!(token.balance(&account), 400);
+    assert_eq!(token.balance(&funder), FUNDER_BALANCE - 400);
+}
+
+#[test]
+fn funding_token_defaults_to_native_asset() {
+    let env = Env::default();
+    let account_wasm = env.deployer().upload_contract_wasm(SMART_ACCOUNT_WASM);
+    let verifier_wasm = env.deployer().upload_contract_wasm(WEBAUTHN_VERIFIER_WASM);
+    let admin = Address::generate(&env);
+
+    let factory = env.register(
+        FACTORY_WASM,
+        (&admin, &account_wasm, &verifier_wasm, None::<Address>),
+    );
+    let factory = FactoryClient::new(&env, &factory);
+
+    let native = env
+        .deployer()
+        .with_stellar_asset([0_u8, 0, 0, 0])
+        .deployed_address();
+    assert_eq!(factory.funding_token(), native);
+    // The factory deployed the native asset contract, so it can be queried.
+    assert_eq!(TokenClient::new(&env, &native).balance(&admin), 0);
+}
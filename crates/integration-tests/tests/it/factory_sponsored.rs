@@ -0,0 +1,95 @@
+use g2c_integration_tests::{deploy_factory, factory_fixture, random_passkey};
+use soroban_sdk::testutils::{Address as _, AuthorizedFunction};
+use soroban_sdk::{symbol_short, Address, Bytes, Env, IntoVal, Symbol};
+
+/// The relayer authorizes (and, as transaction source, pays for) the whole
+/// deployment; the funder's authorization covers only
+/// `(key, credential_id, amount)` and
+/// the resulting token transfer.
+#[test]
+fn relayer_deploys_while_funder_only_authorizes_transfer() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let relayer = Address::generate(&env);
+
+    let (_, key) = random_passkey(&env);
+    let credential_id = Some(Bytes::from_array(&env, &[0xEF; 16]));
+    let account = factory.create_account_sponsored(&relayer, &funder, &key, &credential_id, &0);
+    let auths = env.auths();
+
+    assert_eq!(account, factory.predict_address(&key, &credential_id));
+
+    let (_, relayer_auth) = auths
+        .iter()
+        .find(|(address, _)| *address == relayer)
+        .expect("relayer authorizes the invocation");
+    assert_eq!(
+        relayer_auth.function,
+        AuthorizedFunction::Contract((
+            factory.address.clone(),
+            Symbol::new(&env, "create_account_sponsored"),
+            (
+                relayer.clone(),
+                funder.clone(),
+                key.clone(),
+                credential_id.clone(),
+                0_i128
+            )
+                .into_val(&env),
+        ))
+    );
+
+    let (_, funder_auth) = auths
+        .iter()
+        .find(|(address, _)| *address == funder)
+        .expect("funder authorizes the transfer");
+    assert_eq!(
+        funder_auth.function,
+        AuthorizedFunction::Contract((
+            factory.address.clone(),
+            Symbol::new(&env, "create_account_sponsored"),
+            (key, credential_id, 0_i128).into_val(&env),
+        ))
+    );
+    // Nothing but the token transfer hangs off the funder's authorization:
+    // the deployment itself is the factory's, not the funder's.
+    assert!(funder_auth
+        .sub_invocations
+        .iter()
+        .all(|invocation| matches!(
+            &invocation.function,
+            AuthorizedFunction::Contract((_, fn_name, _)) if *fn_name == symbol_short!("transfer")
+        )));
+}
+
+#[test]
+fn sponsored_creation_requires_funder_auth() {
+    let env = Env::default();
+    let (factory, _admin) = deploy_factory(&env);
+    let relayer = Address::generate(&env);
+    let funder = Address::generate(&env);
+
+    let (_, key) = random_passkey(&env);
+
+    // Only the relayer has signed.
+    env.mock_auths(&[soroban_sdk::testutils::MockAuth {
+        address: &relayer,
+        invoke: &soroban_sdk::testutils::MockAuthInvoke {
+            contract: &factory.address,
+            fn_name: "create_account_sponsored",
+            args: (
+                relayer.clone(),
+                funder.clone(),
+                key.clone(),
+                None::<Bytes>,
+                0_i128,
+            )
+                .into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+
+    assert!(factory
+        .try_create_account_sponsored(&relayer, &funder, &key, &None, &0)
+        .is_err());
+}
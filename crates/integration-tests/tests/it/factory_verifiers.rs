@@ -0,0 +1,135 @@
+use g2c_integration_tests::{
+    deploy_factory, factory_fixture, random_passkey, SmartAccountClient, ED25519_VERIFIER_WASM,
+    WEBAUTHN_VERIFIER_WASM,
+};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{symbol_short, vec, Bytes, BytesN, Env};
+use stellar_accounts::smart_account::Signer;
+
+/// Split key data into the passkey and credential ID arguments that make
+/// the factory use it verbatim.
+fn as_key_data(bytes: &Bytes) -> (BytesN<65>, Option<Bytes>) {
+    let key = bytes.slice(0..65).try_into().unwrap();
+    (key, Some(bytes.slice(65..)))
+}
+
+#[test]
+fn verifier_address_is_known_before_deployment() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let kind = symbol_short!("webauthn");
+
+    let predicted = factory.get_verifier_address(&kind);
+    assert!(!factory.is_verifier_deployed(&kind));
+
+    let (_, key) = random_passkey(&env);
+    let account = factory.create_account(&funder, &key, &None, &0, &None);
+
+    assert!(factory.is_verifier_deployed(&kind));
+    let signers = SmartAccountClient::new(&env, &account)
+        .get_context_rule(&0)
+        .signers;
+    assert_eq!(
+        signers,
+        vec![&env, Signer::External(predicted, key.to_bytes())]
+    );
+}
+
+#[test]
+fn ensure_verifier_deploys_once() {
+    let env = Env::default();
+    let (factory, _admin) = deploy_factory(&env);
+    let kind = symbol_short!("webauthn");
+
+    let address = factory.ensure_verifier(&kind);
+
+    assert_eq!(address, factory.get_verifier_address(&kind));
+    assert!(factory.is_verifier_deployed(&kind));
+    assert_eq!(factory.ensure_verifier(&kind), address);
+}
+
+#[test]
+fn lists_registered_kinds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (factory, _admin) = deploy_factory(&env);
+    let wasm = env.deployer().upload_contract_wasm(ED25519_VERIFIER_WASM);
+    let ed25519 = symbol_short!("ed25519");
+
+    assert_eq!(
+        factory.get_verifier_kinds(),
+        vec![&env, symbol_short!("webauthn")]
+    );
+    assert!(factory.try_get_verifier_address(&ed25519).is_err());
+
+    factory.register_verifier(&ed25519, &wasm);
+    assert_eq!(
+        factory.get_verifier_kinds(),
+        vec![&env, symbol_short!("webauthn"), ed25519.clone()]
+    );
+
+    factory.remove_verifier(&ed25519);
+    assert_eq!(
+        factory.get_verifier_kinds(),
+        vec![&env, symbol_short!("webauthn")]
+    );
+}
+
+/// Verifiers for other kinds are salted with the kind too, so registering a
+/// kind with the WebAuthn WASM does not alias the WebAuthn verifier.
+#[test]
+fn kinds_sharing_a_wasm_get_separate_verifiers() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (factory, _admin) = deploy_factory(&env);
+    let wasm = env.deployer().upload_contract_wasm(WEBAUTHN_VERIFIER_WASM);
+    let webauthn = symbol_short!("webauthn");
+    let other = symbol_short!("passkey2");
+
+    factory.register_verifier(&other, &wasm);
+
+    assert_ne!(
+        factory.get_verifier_address(&other),
+        factory.get_verifier_address(&webauthn)
+    );
+    assert_ne!(
+        factory.ensure_verifier(&other),
+        factory.ensure_verifier(&webauthn)
+    );
+}
+
+/// Key-derived accounts and verifiers both deploy from the factory. Key data
+/// equal to a verifier's salt preimage, under the current or an untagged
+/// scheme, must not give an account the verifier's address.
+#[test]
+fn key_accounts_cannot_take_verifier_addresses() {
+    let env = Env::default();
+    let (factory, funder) = factory_fixture(&env);
+    let ed25519 = symbol_short!("ed25519");
+    let wasm = env.deployer().upload_contract_wasm(ED25519_VERIFIER_WASM);
+    factory.register_verifier(&ed25519, &wasm);
+    // Registering deploys the verifier, so its address is never free.
+    assert!(factory.is_verifier_deployed(&ed25519));
+    let verifier = factory.get_verifier_address(&ed25519);
+
+    let preimages = [
+        (ed25519.clone(), wasm.clone()).to_xdr(&env),
+        (symbol_short!("verifier"), ed25519.clone(), wasm).to_xdr(&env),
+    ];
+    for preimage in preimages {
+        let (key, credential_id) = as_key_data(&preimage);
+        assert_ne!(factory.predict_address(&key, &credential_id), verifier);
+        let account = factory.create_account_for_key(&key, &credential_id, &funder, &0);
+        assert_ne!(account, verifier);
+    }
+    assert_eq!(factory.ensure_verifier(&ed25519), verifier);
+
+    // The WebAuthn verifier used to be salted with its WASM hash, the
+    // sha256 of the WASM itself.
+    let webauthn = symbol_short!("webauthn");
+    let (key, credential_id) = as_key_data(&Bytes::from_slice(&env, WEBAUTHN_VERIFIER_WASM));
+    assert_ne!(
+        factory.predict_address(&key, &credential_id),
+        factory.get_verifier_address(&webauthn)
+    );
+}
@@ -1,3 +1,21 @@
 mod contract_verifier;
+mod factory_accounts;
+mod factory_batch;
+mod factory_config;
+mod factory_events;
+mod factory_fees;
+mod factory_gifts;
+mod factory_pause;
+mod factory_registry;
+mod factory_setup;
+mod factory_sponsored;
+mod factory_verifiers;
 mod smart_account_auth;
+mod smart_account_execute;
+mod smart_account_recovery;
+mod smart_account_sessions;
 mod smart_account_setup;
+mod smart_account_timelock;
+mod smart_account_upgrade;
+mod spending_limit_policy;
+mod threshold_policy;
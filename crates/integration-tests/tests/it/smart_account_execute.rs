@@ -0,0 +1,140 @@
+use g2c_integration_tests::{deploy_smart_account, CallResult};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{symbol_short, vec, Address, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+/// A SEP-41 `transfer` call as `execute_batch` takes it.
+fn transfer(
+    env: &Env,
+    token: &Address,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+) -> (Address, Symbol, Vec<Val>) {
+    (
+        token.clone(),
+        symbol_short!("transfer"),
+        vec![
+            env,
+            from.into_val(env),
+            to.into_val(env),
+            amount.into_val(env),
+        ],
+    )
+}
+
+#[test]
+fn batch_runs_every_call_under_one_auth() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, account, _verifier, _signing_key) = deploy_smart_account(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&account, &100);
+    let recipients = [(); 3].map(|()| Address::generate(&env));
+
+    let mut calls = Vec::new(&env);
+    for recipient in &recipients {
+        calls.push_back(transfer(&env, &token, &account, recipient, 20));
+    }
+    calls.push_back((
+        token.clone(),
+        symbol_short!("balance"),
+        vec![&env, account.into_val(&env)],
+    ));
+    let results = client.execute_batch(&calls);
+
+    // Only the account authorized, once, for the whole batch.
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, account);
+
+    assert_eq!(results.len(), 4);
+    assert_eq!(
+        i128::try_from_val(&env, &results.get(3).unwrap()).unwrap(),
+        40
+    );
+    let token_client = TokenClient::new(&env, &token);
+    for recipient in &recipients {
+        assert_eq!(token_client.balance(recipient), 20);
+    }
+}
+
+#[test]
+fn failing_call_reverts_the_batch() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, account, _verifier, _signing_key) = deploy_smart_account(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&account, &100);
+    let recipient = Address::generate(&env);
+
+    let calls = vec![
+        &env,
+        transfer(&env, &token, &account, &recipient, 60),
+        transfer(&env, &token, &account, &recipient, 60),
+    ];
+    assert!(client.try_execute_batch(&calls).is_err());
+
+    let token_client = TokenClient::new(&env, &token);
+    assert_eq!(token_client.balance(&account), 100);
+    assert_eq!(token_client.balance(&recipient), 0);
+}
+
+#[test]
+fn execute_returns_the_target_result() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, account, _verifier, _signing_key) = deploy_smart_account(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&account, &100);
+
+    let balance = client.execute(
+        &token,
+        &symbol_short!("balance"),
+        &vec![&env, account.into_val(&env)],
+    );
+    assert_eq!(i128::try_from_val(&env, &balance).unwrap(), 100);
+}
+
+#[test]
+fn try_invoke_reports_target_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, account, _verifier, _signing_key) = deploy_smart_account(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+    StellarAssetClient::new(&env, &token).mint(&account, &100);
+    let recipient = Address::generate(&env);
+    let balances = TokenClient::new(&env, &token);
+
+    let (target, target_fn, target_args) = transfer(&env, &token, &account, &recipient, 20);
+    assert!(matches!(
+        client.try_invoke(&target, &target_fn, &target_args),
+        CallResult::Success(_)
+    ));
+    assert_eq!(balances.balance(&recipient), 20);
+
+    // The token's own BalanceError code comes back as the call's result,
+    // distinct from the account's errors, and the transfer is rolled back.
+    let (target, target_fn, target_args) = transfer(&env, &token, &account, &recipient, 1_000);
+    assert!(matches!(
+        client.try_invoke(&target, &target_fn, &target_args),
+        CallResult::ContractError(10)
+    ));
+    assert_eq!(balances.balance(&recipient), 20);
+
+    // G2CAccountError::CallFailed
+    assert_eq!(
+        client
+            .try_try_invoke(&token, &symbol_short!("nope"), &vec![&env])
+            .err(),
+        Some(Ok(soroban_sdk::Error::from_contract_error(15)))
+    );
+}
@@ -0,0 +1,266 @@
+use g2c_integration_tests::{
+    ContextRuleParams, RecoveryConfig, SmartAccountClient, SMART_ACCOUNT_WASM,
+};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{vec, Address, Bytes, Env, Map, Vec};
+use stellar_accounts::smart_account::Signer;
+
+const DELAY: u32 = 100;
+
+struct Setup<'a> {
+    client: SmartAccountClient<'a>,
+    owner: Signer,
+    guardians: [Signer; 3],
+}
+
+/// Deploy an account owned by a delegated signer with 2-of-3 delegated
+/// guardians.
+fn setup(env: &Env) -> Setup<'_> {
+    let owner = Signer::Delegated(Address::generate(env));
+    let signers = vec![env, owner.clone()];
+    let policies: Map<Address, soroban_sdk::Val> = Map::new(env);
+    let context_rules: Vec<ContextRuleParams> = Vec::new(env);
+    let account = env.register(SMART_ACCOUNT_WASM, (&signers, &policies, &context_rules));
+    let client = SmartAccountClient::new(env, &account);
+
+    let guardians = [(); 3].map(|()| Signer::Delegated(Address::generate(env)));
+    client.set_recovery(&Some(RecoveryConfig {
+        guardians: Vec::from_array(env, guardians.clone()),
+        threshold: 2,
+        delay: DELAY,
+    }));
+    Setup {
+        client,
+        owner,
+        guardians,
+    }
+}
+
+#[test]
+fn guardians_replace_the_signers_after_the_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let Setup {
+        client,
+        owner,
+        guardians,
+    } = setup(&env);
+    let new_owner = Signer::Delegated(Address::generate(&env));
+    let new_signers = vec![&env, new_owner.clone()];
+    let no_sig = Bytes::new(&env);
+
+    client.initiate_recovery(&guardians[0], &new_signers, &no_sig);
+    assert_eq!(
+        client
+            .get_active_recovery(&new_signers)
+            .unwrap()
+            .ready_ledger,
+        None
+    );
+
+    client.approve_recovery(&guardians[2], &new_signers, &no_sig);
+    let ready_ledger = env.ledger().sequence() + DELAY;
+    assert_eq!(
+        client
+            .get_active_recovery(&new_signers)
+            .unwrap()
+            .ready_ledger,
+        Some(ready_ledger)
+    );
+
+    // G2CAccountError::RecoveryNotReady
+    assert_eq!(
+        client.try_execute_recovery(&new_signers),
+        Err(Ok(soroban_sdk::Error::from_contract_error(12)))
+    );
+
+    env.ledger().set_sequence_number(ready_ledger);
+    client.execute_recovery(&new_signers);
+
+    let signers = client.get_context_rule(&0).signers;
+    assert_eq!(signers, new_signers);
+    assert!(!signers.contains(&owner));
+    assert!(client.get_active_recovery(&new_signers).is_none());
+}
+
+#[test]
+fn one_approval_is_not_enough() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let Setup {
+        client, guardians, ..
+    } = setup(&env);
+    let new_signers = vec![&env, Signer::Delegated(Address::generate(&env))];
+    let no_sig = Bytes::new(&env);
+
+    client.initiate_recovery(&guardians[1], &new_signers, &no_sig);
+    // Approving twice does not count twice.
+    client.approve_recovery(&guardians[1], &new_signers, &no_sig);
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + DELAY);
+
+    // G2CAccountError::RecoveryNotReady
+    assert_eq!(
+        client.try_execute_recovery(&new_signers),
+        Err(Ok(soroban_sdk::Error::from_contract_error(12)))
+    );
+}
+
+#[test]
+fn owner_vetoes_a_recovery() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let Setup {
+        client,
+        owner,
+        guardians,
+    } = setup(&env);
+    let new_signers = vec![&env, Signer::Delegated(Address::generate(&env))];
+    let no_sig = Bytes::new(&env);
+
+    let first_payload = client.recovery_payload(&new_signers);
+    client.initiate_recovery(&guardians[0], &new_signers, &no_sig);
+    client.approve_recovery(&guardians[1], &new_signers, &no_sig);
+    client.cancel_recovery();
+
+    assert!(client.get_active_recovery(&new_signers).is_none());
+    // Approvals signed for the cancelled recovery cannot be replayed.
+    assert_ne!(client.recovery_payload(&new_signers), first_payload);
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + DELAY);
+    // G2CAccountError::NoActiveRecovery
+    assert_eq!(
+        client.try_execute_recovery(&new_signers),
+        Err(Ok(soroban_sdk::Error::from_contract_error(11)))
+    );
+    assert_eq!(client.get_context_rule(&0).signers, vec![&env, owner]);
+}
+
+#[test]
+fn only_guardians_recover() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let Setup {
+        client,
+        owner,
+        guardians,
+    } = setup(&env);
+    let new_signers = vec![&env, Signer::Delegated(Address::generate(&env))];
+    let no_sig = Bytes::new(&env);
+
+    // G2CAccountError::NotAGuardian
+    assert_eq!(
+        client.try_initiate_recovery(&owner, &new_signers, &no_sig),
+        Err(Ok(soroban_sdk::Error::from_contract_error(9)))
+    );
+
+    client.initiate_recovery(&guardians[0], &new_signers, &no_sig);
+    // G2CAccountError::RecoveryInProgress: the others approve it instead.
+    assert_eq!(
+        client.try_initiate_recovery(&guardians[1], &new_signers, &no_sig),
+        Err(Ok(soroban_sdk::Error::from_contract_error(10)))
+    );
+}
+
+/// A guardian proposing signers nobody else approves does not stop the
+/// other guardians from recovering the account.
+#[test]
+fn one_guardian_cannot_block_recovery() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let Setup {
+        client, guardians, ..
+    } = setup(&env);
+    let no_sig = Bytes::new(&env);
+
+    let hijack = vec![&env, Signer::Delegated(Address::generate(&env))];
+    client.initiate_recovery(&guardians[0], &hijack, &no_sig);
+
+    let new_signers = vec![&env, Signer::Delegated(Address::generate(&env))];
+    client.initiate_recovery(&guardians[1], &new_signers, &no_sig);
+    client.approve_recovery(&guardians[2], &new_signers, &no_sig);
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + DELAY);
+    client.execute_recovery(&new_signers);
+
+    assert_eq!(client.get_context_rule(&0).signers, new_signers);
+    // The rejected proposal is retired with the executed one.
+    assert!(client.get_active_recovery(&hijack).is_none());
+    // G2CAccountError::NoActiveRecovery
+    assert_eq!(
+        client.try_approve_recovery(&guardians[1], &hijack, &no_sig),
+        Err(Ok(soroban_sdk::Error::from_contract_error(11)))
+    );
+}
+
+#[test]
+fn threshold_must_fit_the_guardians() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let Setup {
+        client, guardians, ..
+    } = setup(&env);
+
+    for threshold in [0, 4] {
+        // G2CAccountError::InvalidRecoveryConfig
+        assert_eq!(
+            client.try_set_recovery(&Some(RecoveryConfig {
+                guardians: Vec::from_array(&env, guardians.clone()),
+                threshold,
+                delay: DELAY,
+            })),
+            Err(Ok(soroban_sdk::Error::from_contract_error(8)))
+        );
+    }
+
+    client.set_recovery(&None);
+    assert!(client.get_recovery().is_none());
+}
+
+/// Without a delay the guardians could replace the signers before the owner
+/// can veto.
+#[test]
+fn recovery_needs_a_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let Setup {
+        client, guardians, ..
+    } = setup(&env);
+
+    // G2CAccountError::InvalidRecoveryConfig
+    assert_eq!(
+        client.try_set_recovery(&Some(RecoveryConfig {
+            guardians: Vec::from_array(&env, guardians.clone()),
+            threshold: 2,
+            delay: 0,
+        })),
+        Err(Ok(soroban_sdk::Error::from_contract_error(8)))
+    );
+    assert_eq!(client.get_recovery().unwrap().delay, DELAY);
+}
+
+#[test]
+fn guardians_must_be_distinct() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let Setup {
+        client, guardians, ..
+    } = setup(&env);
+
+    // Two real guardians listed three times could never reach 3-of-3.
+    // G2CAccountError::InvalidRecoveryConfig
+    assert_eq!(
+        client.try_set_recovery(&Some(RecoveryConfig {
+            guardians: vec![
+                &env,
+                guardians[0].clone(),
+                guardians[1].clone(),
+                guardians[0].clone(),
+            ],
+            threshold: 3,
+            delay: DELAY,
+        })),
+        Err(Ok(soroban_sdk::Error::from_contract_error(8)))
+    );
+}
@@ -0,0 +1,209 @@
+use ed25519_dalek::{Signer as _, SigningKey};
+use g2c_integration_tests::{
+    deploy_smart_account, AdminOp, SmartAccountClient, ED25519_VERIFIER_WASM,
+};
+use soroban_sdk::auth::{Context, ContractContext};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{symbol_short, vec, Address, Bytes, BytesN, Env, IntoVal, InvokeError, Map};
+use stellar_accounts::smart_account::{Signatures, Signer, SmartAccountError};
+
+const SESSION_LEDGERS: u32 = 100;
+/// Mirrors the account's `MAX_SESSION_LEDGERS`.
+const MAX_SESSION_LEDGERS: u32 = 120_960;
+
+struct Session<'a> {
+    client: SmartAccountClient<'a>,
+    account: Address,
+    verifier: Address,
+    dapp: Address,
+    key: SigningKey,
+    rule_id: u32,
+}
+
+/// Grant an Ed25519 session key for a fresh dApp contract on a passkey account.
+fn grant(env: &Env) -> Session<'_> {
+    env.mock_all_auths();
+    let (client, account, _passkey_verifier, _passkey) = deploy_smart_account(env);
+    let verifier = env.register(ED25519_VERIFIER_WASM, ());
+    client.set_session_verifier(&verifier);
+
+    let dapp = Address::generate(env);
+    let key = SigningKey::generate(&mut p256::elliptic_curve::rand_core::OsRng);
+    let public_key = BytesN::from_array(env, &key.verifying_key().to_bytes());
+    let valid_until = env.ledger().sequence() + SESSION_LEDGERS;
+    let rule_id = client.grant_session(&dapp, &public_key, &valid_until, &Map::new(env));
+    Session {
+        client,
+        account,
+        verifier,
+        dapp,
+        key,
+        rule_id,
+    }
+}
+
+/// Run the account's `__check_auth` for a call to `contract` signed by `key`.
+fn check_auth(
+    env: &Env,
+    session: &Session,
+    key: &SigningKey,
+    contract: &Address,
+) -> Result<(), Result<SmartAccountError, InvokeError>> {
+    let hash = env.crypto().sha256(&Bytes::from_array(env, &[0x11; 32]));
+    let signature = key.sign(&hash.to_array()).to_bytes();
+    let signer = Signer::External(
+        session.verifier.clone(),
+        Bytes::from_array(env, &key.verifying_key().to_bytes()),
+    );
+    let mut sig_map: Map<Signer, Bytes> = Map::new(env);
+    sig_map.set(signer, Bytes::from_array(env, &signature));
+
+    let context = Context::Contract(ContractContext {
+        contract: contract.clone(),
+        fn_name: symbol_short!("swap"),
+        args: vec![env],
+    });
+    env.try_invoke_contract_check_auth::<SmartAccountError>(
+        &session.account,
+        &hash.to_bytes(),
+        Signatures(sig_map).into_val(env),
+        &vec![env, context],
+    )
+}
+
+#[test]
+fn session_key_signs_for_its_contract_only() {
+    let env = Env::default();
+    let session = grant(&env);
+
+    assert!(check_auth(&env, &session, &session.key, &session.dapp).is_ok());
+
+    let other = Address::generate(&env);
+    assert!(check_auth(&env, &session, &session.key, &other).is_err());
+}
+
+#[test]
+fn session_expires() {
+    let env = Env::default();
+    let session = grant(&env);
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + SESSION_LEDGERS + 1);
+    assert!(check_auth(&env, &session, &session.key, &session.dapp).is_err());
+}
+
+#[test]
+fn revoked_session_stops_signing() {
+    let env = Env::default();
+    let session = grant(&env);
+    let rules = session.client.get_context_rules_count();
+
+    session.client.revoke_session(&session.rule_id);
+    assert_eq!(session.client.get_context_rules_count(), rules - 1);
+    assert!(check_auth(&env, &session, &session.key, &session.dapp).is_err());
+
+    // G2CAccountError::NotASession
+    assert_eq!(
+        session.client.try_revoke_session(&session.rule_id),
+        Err(Ok(soroban_sdk::Error::from_contract_error(14)))
+    );
+    assert_eq!(
+        session.client.try_revoke_session(&0),
+        Err(Ok(soroban_sdk::Error::from_contract_error(14)))
+    );
+}
+
+#[test]
+fn other_ed25519_keys_are_rejected() {
+    let env = Env::default();
+    let session = grant(&env);
+    let stranger = SigningKey::generate(&mut p256::elliptic_curve::rand_core::OsRng);
+
+    assert!(check_auth(&env, &session, &stranger, &session.dapp).is_err());
+}
+
+#[test]
+fn grant_needs_a_session_verifier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _account, _verifier, _passkey) = deploy_smart_account(&env);
+    let key = BytesN::from_array(&env, &[7; 32]);
+
+    // G2CAccountError::NoSessionVerifier
+    assert_eq!(
+        client.try_grant_session(
+            &Address::generate(&env),
+            &key,
+            &(env.ledger().sequence() + SESSION_LEDGERS),
+            &Map::new(&env),
+        ),
+        Err(Ok(soroban_sdk::Error::from_contract_error(13)))
+    );
+}
+
+/// A session on the account itself would let its key authorize the
+/// account's own admin entrypoints.
+#[test]
+fn sessions_cannot_target_the_account() {
+    let env = Env::default();
+    let session = grant(&env);
+    let key = BytesN::from_array(&env, &[7; 32]);
+
+    // G2CAccountError::InvalidSession
+    assert_eq!(
+        session.client.try_grant_session(
+            &session.account,
+            &key,
+            &(env.ledger().sequence() + SESSION_LEDGERS),
+            &Map::new(&env),
+        ),
+        Err(Ok(soroban_sdk::Error::from_contract_error(16)))
+    );
+}
+
+#[test]
+fn session_expiry_must_be_near_and_in_the_future() {
+    let env = Env::default();
+    let session = grant(&env);
+    let key = BytesN::from_array(&env, &[7; 32]);
+    let now = env.ledger().sequence();
+
+    for valid_until in [now, now + MAX_SESSION_LEDGERS + 1, u32::MAX] {
+        // G2CAccountError::InvalidSession
+        assert_eq!(
+            session
+                .client
+                .try_grant_session(&session.dapp, &key, &valid_until, &Map::new(&env)),
+            Err(Ok(soroban_sdk::Error::from_contract_error(16)))
+        );
+    }
+    session.client.grant_session(
+        &session.dapp,
+        &key,
+        &(now + MAX_SESSION_LEDGERS),
+        &Map::new(&env),
+    );
+}
+
+#[test]
+fn session_verifier_changes_wait_for_the_admin_delay() {
+    let env = Env::default();
+    let session = grant(&env);
+    let delay = 100;
+    session.client.set_admin_delay(&delay);
+    let verifier = Address::generate(&env);
+
+    // G2CAccountError::DelayActive
+    assert_eq!(
+        session.client.try_set_session_verifier(&verifier),
+        Err(Ok(soroban_sdk::Error::from_contract_error(3)))
+    );
+
+    let id = session
+        .client
+        .schedule(&AdminOp::SetSessionVerifier(verifier.clone()));
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + delay);
+    session.client.execute_scheduled(&id);
+    assert_eq!(session.client.get_session_verifier(), Some(verifier));
+}
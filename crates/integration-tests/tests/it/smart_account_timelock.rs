@@ -0,0 +1,125 @@
+use g2c_integration_tests::{
+    build_contract_assertion, deploy_smart_account, AdminOp, ContextRuleParams, SmartAccountClient,
+    SMART_ACCOUNT_WASM,
+};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{vec, Address, Bytes, Env, Map, Vec};
+use stellar_accounts::smart_account::Signer;
+use stellar_accounts::verifiers::webauthn::WebAuthnSigData;
+
+const DELAY: u32 = 100;
+
+/// Deploy an account whose only signer is the delegated `owner`.
+fn deploy_delegated_account<'a>(env: &Env, owner: &Address) -> SmartAccountClient<'a> {
+    let signers = vec![env, Signer::Delegated(owner.clone())];
+    let policies: Map<Address, soroban_sdk::Val> = Map::new(env);
+    let context_rules: Vec<ContextRuleParams> = Vec::new(env);
+    let account = env.register(SMART_ACCOUNT_WASM, (&signers, &policies, &context_rules));
+    SmartAccountClient::new(env, &account)
+}
+
+#[test]
+fn delayed_operations_wait_for_the_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let owner = Address::generate(&env);
+    let client = deploy_delegated_account(&env, &owner);
+    client.set_admin_delay(&DELAY);
+    let new_signer = Signer::Delegated(Address::generate(&env));
+
+    // G2CAccountError::DelayActive
+    assert_eq!(
+        client.try_add_signer(&0, &new_signer),
+        Err(Ok(soroban_sdk::Error::from_contract_error(3)))
+    );
+
+    let id = client.schedule(&AdminOp::AddSigner(0, new_signer.clone()));
+    assert_eq!(client.get_pending_operations().len(), 1);
+    assert_eq!(
+        client.get_scheduled_operation(&id).unwrap().ready_ledger,
+        env.ledger().sequence() + DELAY
+    );
+    // G2CAccountError::OperationNotReady
+    assert_eq!(
+        client.try_execute_scheduled(&id),
+        Err(Ok(soroban_sdk::Error::from_contract_error(5)))
+    );
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + DELAY);
+    client.execute_scheduled(&id);
+
+    assert!(client.get_context_rule(&0).signers.contains(&new_signer));
+    assert!(client.get_pending_operations().is_empty());
+}
+
+#[test]
+fn delay_changes_are_scheduled_too() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let owner = Address::generate(&env);
+    let client = deploy_delegated_account(&env, &owner);
+    client.set_admin_delay(&DELAY);
+
+    assert!(client.try_set_admin_delay(&0).is_err());
+
+    let id = client.schedule(&AdminOp::SetDelay(0));
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + DELAY);
+    client.execute_scheduled(&id);
+    assert_eq!(client.get_admin_delay(), 0);
+}
+
+#[test]
+fn delegated_signer_cancels() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let owner = Address::generate(&env);
+    let client = deploy_delegated_account(&env, &owner);
+    client.set_admin_delay(&DELAY);
+    let id = client.schedule(&AdminOp::RemoveSigner(0, Signer::Delegated(owner.clone())));
+
+    // G2CAccountError::NotASigner
+    let stranger = Signer::Delegated(Address::generate(&env));
+    assert_eq!(
+        client.try_cancel_scheduled(&id, &stranger, &Bytes::new(&env)),
+        Err(Ok(soroban_sdk::Error::from_contract_error(6)))
+    );
+
+    client.cancel_scheduled(&id, &Signer::Delegated(owner), &Bytes::new(&env));
+    assert!(client.get_scheduled_operation(&id).is_none());
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + DELAY);
+    assert!(client.try_execute_scheduled(&id).is_err());
+}
+
+#[test]
+fn passkey_signer_cancels_with_assertion() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _account, verifier, signing_key) = deploy_smart_account(&env);
+    client.set_admin_delay(&DELAY);
+    let attacker = Signer::Delegated(Address::generate(&env));
+    let id = client.schedule(&AdminOp::AddSigner(0, attacker));
+
+    let payload = client.cancel_payload(&id);
+    let assertion = build_contract_assertion(&signing_key, &env, &payload.to_array());
+    let sig_data = WebAuthnSigData {
+        signature: assertion.signature,
+        authenticator_data: assertion.authenticator_data,
+        client_data: assertion.client_data,
+    }
+    .to_xdr(&env);
+    let signer = Signer::External(verifier, assertion.key_data);
+
+    // A signature over another operation's payload is rejected.
+    let other = client.schedule(&AdminOp::SetDelay(0));
+    assert!(client
+        .try_cancel_scheduled(&other, &signer, &sig_data)
+        .is_err());
+
+    client.cancel_scheduled(&id, &signer, &sig_data);
+    assert!(client.get_scheduled_operation(&id).is_none());
+    assert_eq!(client.get_pending_operations().len(), 1);
+}
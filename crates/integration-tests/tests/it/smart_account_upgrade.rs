@@ -0,0 +1,56 @@
+use g2c_integration_tests::{
+    deploy_factory, deploy_smart_account, SMART_ACCOUNT_WASM, WEBAUTHN_VERIFIER_WASM,
+};
+use soroban_sdk::Env;
+
+#[test]
+fn constructor_records_schema_version() {
+    let env = Env::default();
+    let (client, _account, _verifier, _signing_key) = deploy_smart_account(&env);
+
+    assert_eq!(client.get_schema_version(), 1);
+    assert_eq!(client.get_upgrade_authority(), None);
+}
+
+#[test]
+fn upgrade_then_migrate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _account, _verifier, _signing_key) = deploy_smart_account(&env);
+    let wasm = env.deployer().upload_contract_wasm(SMART_ACCOUNT_WASM);
+
+    client.upgrade(&wasm);
+    client.migrate();
+
+    assert_eq!(client.get_schema_version(), 1);
+    assert_eq!(client.get_context_rules_count(), 1);
+}
+
+#[test]
+fn upgrade_requires_account_auth() {
+    let env = Env::default();
+    let (client, _account, _verifier, _signing_key) = deploy_smart_account(&env);
+    let wasm = env.deployer().upload_contract_wasm(SMART_ACCOUNT_WASM);
+
+    assert!(client.try_upgrade(&wasm).is_err());
+}
+
+#[test]
+fn upgrade_authority_restricts_hashes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (factory, _admin) = deploy_factory(&env);
+    let (client, _account, _verifier, _signing_key) = deploy_smart_account(&env);
+    client.set_upgrade_authority(&Some(factory.address.clone()));
+
+    let unapproved = env.deployer().upload_contract_wasm(WEBAUTHN_VERIFIER_WASM);
+    assert!(!factory.is_approved_account_wasm(&unapproved));
+    assert_eq!(
+        client.try_upgrade(&unapproved),
+        Err(Ok(soroban_sdk::Error::from_contract_error(1)))
+    );
+
+    let approved = factory.get_account_wasm();
+    assert!(factory.is_approved_account_wasm(&approved));
+    client.upgrade(&approved);
+}
@@ -0,0 +1,327 @@
+use g2c_integration_tests::{
+    deploy_smart_account, passkey_signature, SmartAccountClient, SpendingLimit,
+    SpendingLimitPolicyClient, SPENDING_LIMIT_POLICY_WASM,
+};
+use soroban_sdk::auth::{Context, ContractContext};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{
+    map, symbol_short, vec, Address, Bytes, Env, IntoVal, InvokeError, Map, Symbol, Val, Vec,
+};
+use stellar_accounts::smart_account::{Signatures, SmartAccountError};
+
+const LIMIT: i128 = 100;
+const PERIOD: u32 = 1_000;
+
+struct Setup<'a> {
+    account: Address,
+    policy: SpendingLimitPolicyClient<'a>,
+    token: Address,
+    signatures: Signatures,
+}
+
+/// Deploy a passkey account whose default rule may move at most `LIMIT` of
+/// `token` per `PERIOD` ledgers, and sign one auth payload with the passkey.
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let (client, account, verifier, signing_key) = deploy_smart_account(env);
+    let policy = env.register(SPENDING_LIMIT_POLICY_WASM, ());
+    let token = Address::generate(env);
+    let limits: Map<Address, SpendingLimit> = map![
+        env,
+        (
+            token.clone(),
+            SpendingLimit {
+                limit: LIMIT,
+                period_ledgers: PERIOD,
+            }
+        )
+    ];
+    client.add_policy(&0, &policy, &limits.into_val(env));
+
+    let (signer, sig_data) = passkey_signature(env, &signing_key, &verifier, &[0xAB; 32]);
+    let mut sig_map: Map<_, Bytes> = Map::new(env);
+    sig_map.set(signer, sig_data);
+    Setup {
+        account,
+        policy: SpendingLimitPolicyClient::new(env, &policy),
+        token,
+        signatures: Signatures(sig_map),
+    }
+}
+
+/// Run the account's `__check_auth` for a single `context`.
+fn check(
+    env: &Env,
+    setup: &Setup,
+    context: Context,
+) -> Result<(), Result<SmartAccountError, InvokeError>> {
+    let hash = env.crypto().sha256(&Bytes::from_array(env, &[0xAB; 32]));
+    env.try_invoke_contract_check_auth::<SmartAccountError>(
+        &setup.account,
+        &hash.to_bytes(),
+        setup.signatures.into_val(env),
+        &vec![env, context],
+    )
+}
+
+/// Arguments of `fn_name(account, recipient, amount)` on a token.
+fn spend_args(env: &Env, setup: &Setup, amount: i128) -> Vec<Val> {
+    vec![
+        env,
+        setup.account.into_val(env),
+        Address::generate(env).into_val(env),
+        amount.into_val(env),
+    ]
+}
+
+/// Run the account's auth check for `token.fn_name(account, recipient, amount)`.
+fn check_spend(
+    env: &Env,
+    setup: &Setup,
+    token: &Address,
+    fn_name: Symbol,
+    amount: i128,
+) -> Result<(), Result<SmartAccountError, InvokeError>> {
+    let context = Context::Contract(ContractContext {
+        contract: token.clone(),
+        fn_name,
+        args: spend_args(env, setup, amount),
+    });
+    check(env, setup, context)
+}
+
+/// Run the account's auth check for `account.fn_name(args)`, i.e. calls the
+/// account makes through its own entrypoints.
+fn check_account_call(
+    env: &Env,
+    setup: &Setup,
+    fn_name: &str,
+    args: Vec<Val>,
+) -> Result<(), Result<SmartAccountError, InvokeError>> {
+    let context = Context::Contract(ContractContext {
+        contract: setup.account.clone(),
+        fn_name: Symbol::new(env, fn_name),
+        args,
+    });
+    check(env, setup, context)
+}
+
+#[test]
+fn spends_up_to_the_limit() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let transfer = symbol_short!("transfer");
+
+    assert!(check_spend(&env, &setup, &setup.token, transfer.clone(), 60).is_ok());
+    assert_eq!(
+        setup.policy.get_remaining(&setup.account, &0, &setup.token),
+        Some(40)
+    );
+    assert!(check_spend(&env, &setup, &setup.token, transfer.clone(), 50).is_err());
+    assert!(check_spend(&env, &setup, &setup.token, transfer, 40).is_ok());
+    assert_eq!(
+        setup.policy.get_remaining(&setup.account, &0, &setup.token),
+        Some(0)
+    );
+}
+
+#[test]
+fn limit_rolls_over_after_the_period() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let transfer = symbol_short!("transfer");
+
+    assert!(check_spend(&env, &setup, &setup.token, transfer.clone(), LIMIT).is_ok());
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + PERIOD - 1);
+    assert!(check_spend(&env, &setup, &setup.token, transfer.clone(), 1).is_err());
+
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + 1);
+    assert!(check_spend(&env, &setup, &setup.token, transfer, LIMIT).is_ok());
+}
+
+#[test]
+fn approvals_count_and_other_tokens_are_unlimited() {
+    let env = Env::default();
+    let setup = setup(&env);
+
+    assert!(check_spend(
+        &env,
+        &setup,
+        &setup.token,
+        symbol_short!("approve"),
+        LIMIT + 1
+    )
+    .is_err());
+    assert!(check_spend(&env, &setup, &setup.token, symbol_short!("approve"), LIMIT).is_ok());
+
+    let other_token = Address::generate(&env);
+    assert!(check_spend(
+        &env,
+        &setup,
+        &other_token,
+        symbol_short!("transfer"),
+        LIMIT * 10
+    )
+    .is_ok());
+    assert!(setup
+        .policy
+        .get_remaining(&setup.account, &0, &other_token)
+        .is_none());
+}
+
+/// A transfer run through the account's `execute` or `try_invoke` only
+/// appears as a context on the account, and still counts against the limit.
+#[test]
+fn spends_through_execute_count() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let transfer = symbol_short!("transfer");
+    let call = |amount: i128| -> Vec<Val> {
+        vec![
+            &env,
+            setup.token.into_val(&env),
+            transfer.into_val(&env),
+            spend_args(&env, &setup, amount).into_val(&env),
+        ]
+    };
+
+    assert!(check_account_call(&env, &setup, "execute", call(LIMIT + 1)).is_err());
+    assert!(check_account_call(&env, &setup, "execute", call(60)).is_ok());
+    assert!(check_account_call(&env, &setup, "try_invoke", call(50)).is_err());
+    assert!(check_account_call(&env, &setup, "try_invoke", call(40)).is_ok());
+    assert_eq!(
+        setup.policy.get_remaining(&setup.account, &0, &setup.token),
+        Some(0)
+    );
+}
+
+#[test]
+fn batched_spends_are_summed() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let transfer = symbol_short!("transfer");
+    let batch = |amounts: [i128; 2]| -> Vec<Val> {
+        let calls = Vec::from_iter(
+            &env,
+            amounts.map(|amount| {
+                (
+                    setup.token.clone(),
+                    transfer.clone(),
+                    spend_args(&env, &setup, amount),
+                )
+            }),
+        );
+        vec![&env, calls.into_val(&env)]
+    };
+
+    assert!(check_account_call(&env, &setup, "execute_batch", batch([60, 60])).is_err());
+    assert!(check_account_call(&env, &setup, "execute_batch", batch([40, 40])).is_ok());
+    assert_eq!(
+        setup.policy.get_remaining(&setup.account, &0, &setup.token),
+        Some(20)
+    );
+}
+
+#[test]
+fn limits_cannot_be_reset_outside_policy_management() {
+    let env = Env::default();
+    let setup = setup(&env);
+    let client = SmartAccountClient::new(&env, &setup.account);
+    assert!(check_spend(&env, &setup, &setup.token, symbol_short!("transfer"), 60).is_ok());
+
+    let rule = client.get_context_rule(&0);
+    let limits: Map<Address, SpendingLimit> = map![
+        &env,
+        (
+            setup.token.clone(),
+            SpendingLimit {
+                limit: LIMIT,
+                period_ledgers: PERIOD,
+            }
+        )
+    ];
+    let uninstall_args: Vec<Val> = vec![&env, rule.into_val(&env), setup.account.into_val(&env)];
+    let install_args: Vec<Val> = vec![
+        &env,
+        limits.into_val(&env),
+        rule.into_val(&env),
+        setup.account.into_val(&env),
+    ];
+    let uninstall = Symbol::new(&env, "uninstall");
+    let install = symbol_short!("install");
+    // G2CAccountError::PolicySetupCall
+    let refused = Err(Ok(soroban_sdk::Error::from_contract_error(17)));
+
+    assert_eq!(
+        client
+            .try_execute(&setup.policy.address, &uninstall, &uninstall_args)
+            .map(|_| ()),
+        refused
+    );
+    assert_eq!(
+        client
+            .try_execute_batch(&vec![
+                &env,
+                (
+                    setup.policy.address.clone(),
+                    uninstall.clone(),
+                    uninstall_args.clone()
+                ),
+                (
+                    setup.policy.address.clone(),
+                    install.clone(),
+                    install_args.clone()
+                ),
+            ])
+            .map(|_| ()),
+        refused
+    );
+
+    // Signing for the call directly is refused as well.
+    let direct = Context::Contract(ContractContext {
+        contract: setup.policy.address.clone(),
+        fn_name: uninstall,
+        args: uninstall_args,
+    });
+    assert!(check(&env, &setup, direct).is_err());
+
+    // Installing over the existing state would wipe the recorded spends.
+    // SpendingLimitError::AlreadyInstalled
+    assert_eq!(
+        env.try_invoke_contract::<(), soroban_sdk::Error>(
+            &setup.policy.address,
+            &install,
+            install_args,
+        ),
+        Err(Ok(soroban_sdk::Error::from_contract_error(3)))
+    );
+
+    assert_eq!(
+        setup.policy.get_remaining(&setup.account, &0, &setup.token),
+        Some(LIMIT - 60)
+    );
+}
+
+#[test]
+fn rejects_invalid_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _account, _verifier, _signing_key) = deploy_smart_account(&env);
+    let policy = env.register(SPENDING_LIMIT_POLICY_WASM, ());
+    let limits: Map<Address, SpendingLimit> = map![
+        &env,
+        (
+            Address::generate(&env),
+            SpendingLimit {
+                limit: 0,
+                period_ledgers: PERIOD,
+            }
+        )
+    ];
+
+    assert!(client
+        .try_add_policy(&0, &policy, &limits.into_val(&env))
+        .is_err());
+}
@@ -0,0 +1,177 @@
+use g2c_integration_tests::{
+    passkey_signature, ContextRuleParams, SmartAccountClient, ThresholdParams,
+    ThresholdPolicyClient, SMART_ACCOUNT_WASM, THRESHOLD_POLICY_WASM, WEBAUTHN_VERIFIER_WASM,
+};
+use p256::ecdsa::SigningKey;
+use soroban_sdk::auth::{Context, ContractContext};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{
+    map, symbol_short, vec, Address, Bytes, Env, IntoVal, InvokeError, Map, Symbol, Val, Vec,
+};
+use stellar_accounts::smart_account::{Signatures, Signer, SmartAccountError};
+
+const PAYLOAD: [u8; 32] = [0x5A; 32];
+
+/// An account whose default rule has three passkey signers and a threshold
+/// policy installed at construction.
+struct MultiPasskeyAccount {
+    account: Address,
+    policy: Address,
+    verifier: Address,
+    keys: [SigningKey; 3],
+}
+
+fn signer(env: &Env, verifier: &Address, key: &SigningKey) -> Signer {
+    let pubkey_sec1 = key.verifying_key().to_sec1_bytes();
+    Signer::External(verifier.clone(), Bytes::from_slice(env, &pubkey_sec1))
+}
+
+fn deploy(env: &Env, params: impl FnOnce(&[Signer; 3]) -> ThresholdParams) -> MultiPasskeyAccount {
+    env.mock_all_auths();
+    let verifier = env.register(WEBAUTHN_VERIFIER_WASM, ());
+    let policy = env.register(THRESHOLD_POLICY_WASM, ());
+    let keys = [(); 3].map(|()| SigningKey::random(&mut p256::elliptic_curve::rand_core::OsRng));
+    let signers = [0, 1, 2].map(|i| signer(env, &verifier, &keys[i]));
+
+    let policies: Map<Address, soroban_sdk::Val> =
+        map![env, (policy.clone(), params(&signers).into_val(env))];
+    let context_rules: Vec<ContextRuleParams> = Vec::new(env);
+    let account = env.register(
+        SMART_ACCOUNT_WASM,
+        (Vec::from_array(env, signers), &policies, &context_rules),
+    );
+    MultiPasskeyAccount {
+        account,
+        policy,
+        verifier,
+        keys,
+    }
+}
+
+/// Run the account's `__check_auth` for a transfer signed by `signed_by`.
+fn check_auth(
+    env: &Env,
+    account: &MultiPasskeyAccount,
+    signed_by: &[usize],
+) -> Result<(), Result<SmartAccountError, InvokeError>> {
+    let mut sig_map: Map<Signer, Bytes> = Map::new(env);
+    for &i in signed_by {
+        let (signer, sig_data) =
+            passkey_signature(env, &account.keys[i], &account.verifier, &PAYLOAD);
+        sig_map.set(signer, sig_data);
+    }
+    let hash = env.crypto().sha256(&Bytes::from_array(env, &PAYLOAD));
+    let context = Context::Contract(ContractContext {
+        contract: Address::generate(env),
+        fn_name: symbol_short!("transfer"),
+        args: vec![env],
+    });
+    env.try_invoke_contract_check_auth::<SmartAccountError>(
+        &account.account,
+        &hash.to_bytes(),
+        Signatures(sig_map).into_val(env),
+        &vec![env, context],
+    )
+}
+
+#[test]
+fn two_of_three_rejects_a_single_passkey() {
+    let env = Env::default();
+    let account = deploy(&env, |_| ThresholdParams::Simple(2));
+
+    assert!(check_auth(&env, &account, &[0]).is_err());
+    assert!(check_auth(&env, &account, &[0, 2]).is_ok());
+    assert!(check_auth(&env, &account, &[0, 1, 2]).is_ok());
+    assert_eq!(
+        ThresholdPolicyClient::new(&env, &account.policy).get_threshold(&account.account, &0),
+        Some(ThresholdParams::Simple(2))
+    );
+}
+
+#[test]
+fn weighted_threshold_counts_weights() {
+    let env = Env::default();
+    let account = deploy(&env, |signers| {
+        let weights = map![
+            &env,
+            (signers[0].clone(), 2),
+            (signers[1].clone(), 1),
+            (signers[2].clone(), 1)
+        ];
+        ThresholdParams::Weighted(weights, 2)
+    });
+
+    assert!(check_auth(&env, &account, &[0]).is_ok());
+    assert!(check_auth(&env, &account, &[1]).is_err());
+    assert!(check_auth(&env, &account, &[1, 2]).is_ok());
+}
+
+#[test]
+fn threshold_is_only_changed_through_policy_management() {
+    let env = Env::default();
+    let account = deploy(&env, |_| ThresholdParams::Simple(2));
+    let client = SmartAccountClient::new(&env, &account.account);
+    let policy = ThresholdPolicyClient::new(&env, &account.policy);
+    let rule = client.get_context_rule(&0);
+    let uninstall_args: Vec<Val> = vec![&env, rule.into_val(&env), account.account.into_val(&env)];
+    let install_args: Vec<Val> = vec![
+        &env,
+        ThresholdParams::Simple(1).into_val(&env),
+        rule.into_val(&env),
+        account.account.into_val(&env),
+    ];
+
+    // G2CAccountError::PolicySetupCall
+    assert_eq!(
+        client
+            .try_execute(
+                &account.policy,
+                &Symbol::new(&env, "uninstall"),
+                &uninstall_args
+            )
+            .map(|_| ()),
+        Err(Ok(soroban_sdk::Error::from_contract_error(17)))
+    );
+    // ThresholdError::AlreadyInstalled
+    assert_eq!(
+        env.try_invoke_contract::<(), soroban_sdk::Error>(
+            &account.policy,
+            &symbol_short!("install"),
+            install_args,
+        ),
+        Err(Ok(soroban_sdk::Error::from_contract_error(3)))
+    );
+    assert!(check_auth(&env, &account, &[0]).is_err());
+
+    client.remove_policy(&0, &account.policy);
+    client.add_policy(
+        &0,
+        &account.policy,
+        &ThresholdParams::Simple(1).into_val(&env),
+    );
+    assert_eq!(
+        policy.get_threshold(&account.account, &0),
+        Some(ThresholdParams::Simple(1))
+    );
+    assert!(check_auth(&env, &account, &[0]).is_ok());
+}
+
+#[test]
+fn rejects_unreachable_thresholds() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let verifier = env.register(WEBAUTHN_VERIFIER_WASM, ());
+    let policy = env.register(THRESHOLD_POLICY_WASM, ());
+    let key = SigningKey::random(&mut p256::elliptic_curve::rand_core::OsRng);
+    let signers = vec![&env, signer(&env, &verifier, &key)];
+    let context_rules: Vec<ContextRuleParams> = Vec::new(&env);
+
+    for params in [ThresholdParams::Simple(0), ThresholdParams::Simple(2)] {
+        let policies: Map<Address, soroban_sdk::Val> =
+            map![&env, (policy.clone(), params.into_val(&env))];
+        let deployed = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            env.register(SMART_ACCOUNT_WASM, (&signers, &policies, &context_rules))
+        }));
+        assert!(deployed.is_err());
+    }
+}
@@ -461,27 +461,614 @@ export interface WebAuthnSigData {
      */
     signature: Buffer;
 }
+/**
+ * An additional context rule for a new account. Mirrors the smart account's
+ * `ContextRuleParams` constructor argument.
+ */
+export interface ContextRuleParams {
+    context_type: ContextRuleType;
+    name: string;
+    policies: Map<string, any>;
+    signers: Array<Signer>;
+    valid_until: Option<u32>;
+}
+/**
+ * A signer to install on a new account. The factory resolves each spec to a
+ * `Signer`, pointing `External` signers at the verifier it manages for the
+ * spec's kind, so callers cannot bind accounts to arbitrary verifiers.
+ */
+export type SignerSpec = {
+    tag: "WebAuthn";
+    values: readonly [Buffer, Option<Buffer>];
+} | {
+    tag: "Ed25519";
+    values: readonly [Buffer];
+} | {
+    tag: "Delegated";
+    values: readonly [string];
+} | {
+    tag: "External";
+    values: readonly [string, Buffer];
+};
+/**
+ * `ContextRuleParams` with signer specs instead of resolved signers.
+ */
+export interface ContextRuleSpec {
+    context_type: ContextRuleType;
+    name: string;
+    policies: Map<string, any>;
+    signers: Array<SignerSpec>;
+    valid_until: Option<u32>;
+}
+/**
+ * Full initial configuration of a smart account: the default rule's signers
+ * and policies (policy address to install param), plus any extra rules.
+ */
+export interface AccountConfig {
+    context_rules: Array<ContextRuleSpec>;
+    policies: Map<string, any>;
+    signers: Array<SignerSpec>;
+}
+/**
+ * Fee the factory charges funders for each account it deploys, in its
+ * funding token. Topping up an existing account is free.
+ */
+export type FeeConfig = {
+    tag: "Disabled";
+    values: void;
+} | {
+    tag: "Flat";
+    values: readonly [i128];
+} | {
+    tag: "Bps";
+    values: readonly [u32];
+};
+/**
+ * Tokens held by the factory until the holder of the claim key creates
+ * their account, or until `expiry_ledger` passes and the sender takes them
+ * back.
+ */
+export interface Gift {
+    amount: i128;
+    /**
+     * Last ledger at which the gift can be claimed.
+     */
+    expiry_ledger: u32;
+    /**
+     * Account fee paid by the sender in the funding token at deposit. It
+     * goes to the treasury if the claim deploys the account and back to the
+     * sender otherwise.
+     */
+    fee: i128;
+    sender: string;
+    token: string;
+}
+export type Entry = {
+    tag: "Key";
+    values: readonly [Buffer];
+} | {
+    tag: "Credential";
+    values: readonly [Buffer];
+};
+export interface Slot {
+    entry: Entry;
+    position: u32;
+}
+export interface Member {
+    account: string;
+    entry: Entry;
+}
+export declare const FactoryError: {
+    /**
+     * An account already exists at the requested address, but the supplied
+     * key is not a signer on its default context rule.
+     */
+    1: {
+        message: string;
+    };
+    /**
+     * An account already exists at the requested address and the entrypoint
+     * cannot top it up.
+     */
+    2: {
+        message: string;
+    };
+    /**
+     * An account configuration has no signers on its default rule.
+     */
+    3: {
+        message: string;
+    };
+    /**
+     * A gift escrow is already open under this claim key.
+     */
+    4: {
+        message: string;
+    };
+    /**
+     * No gift escrow is open under this claim key.
+     */
+    5: {
+        message: string;
+    };
+    /**
+     * The gift's expiry ledger has passed, or would already have passed
+     * when depositing.
+     */
+    6: {
+        message: string;
+    };
+    /**
+     * The gift can still be claimed, so it cannot be refunded yet.
+     */
+    7: {
+        message: string;
+    };
+    /**
+     * A batch has more entries than one call may deploy.
+     */
+    8: {
+        message: string;
+    };
+    /**
+     * A flat fee is negative or a basis-point fee exceeds 10 000.
+     */
+    9: {
+        message: string;
+    };
+    /**
+     * The caller is neither the admin nor the guardian.
+     */
+    10: {
+        message: string;
+    };
+    /**
+     * The factory is paused and does not create or fund accounts.
+     */
+    11: {
+        message: string;
+    };
+    /**
+     * A signer spec names a verifier kind the factory has no WASM for.
+     */
+    12: {
+        message: string;
+    };
+    /**
+     * The signer hooks were called by an account this factory did not
+     * deploy.
+     */
+    13: {
+        message: string;
+    };
+    /**
+     * The key data to index is not a signer on the account's default rules.
+     */
+    14: {
+        message: string;
+    };
+    /**
+     * The key data to drop from the index is still a signer on the account.
+     */
+    15: {
+        message: string;
+    };
+    /**
+     * A gift amount is zero or negative.
+     */
+    16: {
+        message: string;
+    };
+};
 export interface Client {
     /**
      * Construct and simulate a create_account transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      * Deploy an account contract and add a passkey to it. Lastly transfer funds to the contract's account.
      *
+     * If the account already exists this only tops it up, provided `key` is a
+     * signer on its default context rule.
+     *
+     * `index` selects which of the funder's accounts to create, so one
+     * G-address can own several C-addresses. `None` is index 0, which keeps
+     * the address the factory has always derived for a funder.
+     *
+     * `credential_id` is the WebAuthn credential ID of the passkey. It is
+     * stored after the public key in the signer's key data so clients can
+     * build `allowCredentials` from on-chain state.
      */
-    create_account: ({ funder, key, amount }: {
+    create_account: ({ funder, key, credential_id, amount, index }: {
         funder: string;
         key: Buffer;
+        credential_id: Option<Buffer>;
         amount: i128;
+        index: Option<u32>;
     }, options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a create_account_with_assets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Same as `create_account`, but funds the account with any number of
+     * SEP-41 tokens (e.g. XLM, USDC, EURC SACs) in a single transaction.
+     * Each `(token, amount)` pair is transferred from `funder` in order.
+     */
+    create_account_with_assets: ({ funder, key, credential_id, assets, index }: {
+        funder: string;
+        key: Buffer;
+        credential_id: Option<Buffer>;
+        assets: Array<readonly [string, i128]>;
+        index: Option<u32>;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a create_account_with_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deploy an account with several initial signers, policies and extra
+     * context rules in one call, then fund it with `amount` of the funding
+     * token. Signers are given as `SignerSpec`s (passkey, Ed25519 key,
+     * delegated address or another registered verifier kind); `External`
+     * specs must name a kind registered with `register_verifier`. Unlike
+     * `create_account`, this fails if the account at `index` already exists,
+     * since an existing configuration cannot be reconciled.
+     */
+    create_account_with_config: ({ funder, config, amount, index }: {
+        funder: string;
+        config: AccountConfig;
+        amount: i128;
+        index: Option<u32>;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a create_accounts transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deploy and fund several of the funder's accounts in one call. Each
+     * entry is `(key, credential_id, amount, index)` and behaves like
+     * `create_account` with those arguments, including topping up accounts
+     * that already exist. At most `MAX_BATCH_SIZE` (3) entries are accepted.
+     */
+    create_accounts: ({ funder, entries }: {
+        funder: string;
+        entries: Array<readonly [Buffer, Option<Buffer>, i128, u32]>;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<Array<string>>>;
     /**
      * Construct and simulate a get_c_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
      */
-    get_c_address: ({ funder }: {
+    get_c_address: ({ funder, index }: {
         funder: string;
+        index: Option<u32>;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a predict_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Predict the C-address of the account owned by `key`. The address only
+     * depends on the factory, the passkey and its credential ID, so it can
+     * be shared and funded before the user has a G-address or the account
+     * exists.
+     */
+    predict_address: ({ key, credential_id }: {
+        key: Buffer;
+        credential_id: Option<Buffer>;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a create_account_for_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deploy the account at `predict_address(key, credential_id)` if it does
+     * not exist yet, then transfer `amount` from `funder` into it. Any funder
+     * can do this, since the address commits to the signer it is created
+     * with.
+     */
+    create_account_for_key: ({ key, credential_id, funder, amount }: {
+        key: Buffer;
+        credential_id: Option<Buffer>;
+        funder: string;
+        amount: i128;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a create_account_sponsored transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Sponsored variant of `create_account_for_key`. `relayer` submits the
+     * transaction and pays its fees; `funder` only signs an authorization
+     * bound to `(key, credential_id, amount)`, so the relayer cannot
+     * redirect the funds or change how much is moved.
+     */
+    create_account_sponsored: ({ relayer, funder, key, credential_id, amount }: {
+        relayer: string;
+        funder: string;
+        key: Buffer;
+        credential_id: Option<Buffer>;
+        amount: i128;
     }, options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a deposit_gift transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Hold `amount` of `token` in escrow until it is claimed with a
+     * signature from the Ed25519 `claim_key`. The gift can be claimed up to
+     * and including `expiry_ledger`; after that only `refund_gift` can
+     * release it. The sender also escrows the account fee, in the funding
+     * token, since the claim may deploy an account.
+     */
+    deposit_gift: ({ sender, token, amount, claim_key, expiry_ledger }: {
+        sender: string;
+        token: string;
+        amount: i128;
+        claim_key: Buffer;
+        expiry_ledger: u32;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a claim_gift transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deploy the account at `predict_address(key, credential_id)` if needed
+     * and move the gift held under `claim_key` into it. No auth is
+     * required: `signature` is the claim key's signature over
+     * `gift_claim_payload(claim_key, key, credential_id)`, which binds the
+     * gift to that account (see the `gift` module docs). The escrowed fee
+     * goes to the treasury if this deploys the account, else to the sender.
+     */
+    claim_gift: ({ claim_key, key, credential_id, signature }: {
+        claim_key: Buffer;
+        key: Buffer;
+        credential_id: Option<Buffer>;
+        signature: Buffer;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a gift_claim_payload transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * The message `claim_key` must sign for `claim_gift` to send its gift
+     * to the account of `key` and `credential_id`.
+     */
+    gift_claim_payload: ({ claim_key, key, credential_id }: {
+        claim_key: Buffer;
+        key: Buffer;
+        credential_id: Option<Buffer>;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>;
+    /**
+     * Construct and simulate a refund_gift transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Return an expired, unclaimed gift and its escrowed fee to the sender.
+     * Anyone may call this, since the funds can only go back to the sender.
+     */
+    refund_gift: ({ claim_key }: {
+        claim_key: Buffer;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a get_gift transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * The open gift escrow under `claim_key`, if any.
+     */
+    get_gift: ({ claim_key }: {
+        claim_key: Buffer;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<Option<Gift>>>;
+    /**
+     * Construct and simulate a get_account_indices transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Indices the funder has already used with `create_account`.
+     */
+    get_account_indices: ({ funder }: {
+        funder: string;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<Array<u32>>>;
+    /**
+     * Construct and simulate a lookup_by_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Accounts that were created with, or have registered, this passkey
+     * public key as a signer. Returns up to `limit` (at most 50) accounts
+     * from position `start`; a shorter page means there are no more.
+     */
+    lookup_by_key: ({ key, start, limit }: {
+        key: Buffer;
+        start: u32;
+        limit: u32;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<Array<string>>>;
+    /**
+     * Construct and simulate a lookup_by_credential transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Accounts that were created with, or have registered, a signer with
+     * this WebAuthn credential ID. Paginated like `lookup_by_key`.
+     */
+    lookup_by_credential: ({ credential_id, start, limit }: {
+        credential_id: Buffer;
+        start: u32;
+        limit: u32;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<Array<string>>>;
+    /**
+     * Construct and simulate a register_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Hook for an account to index a signer it added. `key_data` is the
+     * `Signer::External` key data: a 65-byte public key optionally followed
+     * by the credential ID. Only accounts this factory deployed may call it,
+     * and `key_data` must be a signer on one of their default context rules.
+     */
+    register_signer: ({ account, key_data }: {
+        account: string;
+        key_data: Buffer;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a unregister_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Hook for an account to drop a signer it removed from the index. Fails
+     * while `key_data` is still a signer on a default context rule.
+     */
+    unregister_signer: ({ account, key_data }: {
+        account: string;
+        key_data: Buffer;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a set_account_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Replace the smart-account WASM used for new deployments. Accounts
+     * already deployed keep their code. Returns the new version number.
+     */
+    set_account_wasm: ({ wasm_hash }: {
+        wasm_hash: Buffer;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a set_verifier_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Replace the WebAuthn verifier WASM and deploy the new verifier. It
+     * lives at a new address; accounts deployed earlier keep pointing at
+     * the old one. Returns the new version number.
+     */
+    set_verifier_wasm: ({ wasm_hash }: {
+        wasm_hash: Buffer;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a set_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set the fee charged for each account the factory deploys and the
+     * treasury it is paid to. The fee is paid in the funding token by the
+     * funder on top of `amount`; top-ups of existing accounts are free.
+     * `FeeConfig::Disabled` turns it off.
+     */
+    set_fee: ({ fee, treasury }: {
+        fee: FeeConfig;
+        treasury: string;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a set_fee_exempt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Exempt `funder` from the fee, or make it pay again.
+     */
+    set_fee_exempt: ({ funder, exempt }: {
+        funder: string;
+        exempt: boolean;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a set_guardian transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Set or clear the guardian, a second address (e.g. an ops key or
+     * monitoring service) that can pause the factory without the admin.
+     */
+    set_guardian: ({ guardian }: {
+        guardian: Option<string>;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Stop all account creation, e.g. when the account WASM has a known
+     * vulnerability. Every `create_account*` entrypoint (top-ups of existing
+     * accounts included), `claim_gift` and `ensure_verifier` fail with
+     * `Paused`; gift deposits and refunds and address lookups keep working.
+     * `caller` must be the admin or the guardian.
+     */
+    pause: ({ caller }: {
+        caller: string;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Resume account creation. Only the admin can unpause, so a guardian key can
+     * stop the factory quickly but not lift a pause on its own.
+     */
+    unpause: (options?: MethodOptions) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    is_paused: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>;
+    /**
+     * Construct and simulate a get_guardian transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_guardian: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>;
+    /**
+     * Construct and simulate a get_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_fee: (options?: MethodOptions) => Promise<AssembledTransaction<FeeConfig>>;
+    /**
+     * Construct and simulate a get_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_treasury: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>;
+    /**
+     * Construct and simulate a is_fee_exempt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    is_fee_exempt: ({ funder }: {
+        funder: string;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>;
+    /**
+     * Construct and simulate a funding_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * The token accounts are funded with.
+     */
+    funding_token: (options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a get_account_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_account_wasm: (options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>;
+    /**
+     * Construct and simulate a get_account_wasm_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_account_wasm_version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a get_account_wasm_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Look up the account WASM hash recorded for a past or current version.
+     */
+    get_account_wasm_at: ({ version }: {
+        version: u32;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<Option<Buffer>>>;
+    /**
+     * Construct and simulate a is_approved_account_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Whether accounts that name the factory as their upgrade authority may
+     * install `wasm_hash`. Only the current account WASM is approved, so
+     * accounts cannot move back to a version the admin has replaced.
+     */
+    is_approved_account_wasm: ({ wasm_hash }: {
+        wasm_hash: Buffer;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>;
+    /**
+     * Construct and simulate a get_verifier_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_verifier_wasm: (options?: MethodOptions) => Promise<AssembledTransaction<Buffer>>;
+    /**
+     * Construct and simulate a get_verifier_wasm_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     */
+    get_verifier_wasm_version: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>;
+    /**
+     * Construct and simulate a get_verifier_wasm_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Look up the verifier WASM hash recorded for a past or current version.
+     */
+    get_verifier_wasm_at: ({ version }: {
+        version: u32;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<Option<Buffer>>>;
+    /**
+     * Construct and simulate a register_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Allow `SignerSpec`s of `kind` and verify them with a shared instance
+     * of `wasm_hash`, which is deployed right away. Registering `webauthn`
+     * is the same as `set_verifier_wasm`.
+     */
+    register_verifier: ({ kind, wasm_hash }: {
+        kind: string;
+        wasm_hash: Buffer;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a remove_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Stop accepting signers of `kind` for new accounts. Accounts already
+     * using the verifier are unaffected. The built-in `webauthn` kind cannot
+     * be removed.
+     */
+    remove_verifier: ({ kind }: {
+        kind: string;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<null>>;
+    /**
+     * Construct and simulate a get_verifier_kinds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Every signer kind new accounts can use, starting with `webauthn`.
+     */
+    get_verifier_kinds: (options?: MethodOptions) => Promise<AssembledTransaction<Array<string>>>;
+    /**
+     * Construct and simulate a get_verifier_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Address of the shared verifier for `kind`, whether or not it has been
+     * deployed yet. Clients can use it to build `Signer::External` values
+     * without a transaction.
+     */
+    get_verifier_address: ({ kind }: {
+        kind: string;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a is_verifier_deployed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Whether the shared verifier for `kind` has been deployed.
+     */
+    is_verifier_deployed: ({ kind }: {
+        kind: string;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>;
+    /**
+     * Construct and simulate a ensure_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * Deploy the shared verifier for `kind` if needed and return its address.
+     * Verifiers are deployed when registered, except the WebAuthn verifier
+     * given to the constructor, which deploys with the first account.
+     */
+    ensure_verifier: ({ kind }: {
+        kind: string;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+    /**
+     * Construct and simulate a get_verifier_kind_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+     * The verifier WASM hash registered for `kind`, if any.
+     */
+    get_verifier_kind_wasm: ({ kind }: {
+        kind: string;
+    }, options?: MethodOptions) => Promise<AssembledTransaction<Option<Buffer>>>;
 }
 export declare class Client extends ContractClient {
     readonly options: ContractClientOptions;
     static deploy<T = Client>(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { admin, account_wasm, verifier_wasm, funding_token }: {
+        admin: string;
+        account_wasm: Buffer;
+        verifier_wasm: Buffer;
+        funding_token: Option<string>;
+    },
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions & Omit<ContractClientOptions, "contractId"> & {
         /** The hash of the Wasm blob, which must already be installed on-chain. */
@@ -494,6 +1081,50 @@ export declare class Client extends ContractClient {
     constructor(options: ContractClientOptions);
     readonly fromJSON: {
         create_account: (json: string) => AssembledTransaction<string>;
+        create_account_with_assets: (json: string) => AssembledTransaction<string>;
+        create_account_with_config: (json: string) => AssembledTransaction<string>;
+        create_accounts: (json: string) => AssembledTransaction<Array<string>>;
         get_c_address: (json: string) => AssembledTransaction<string>;
+        predict_address: (json: string) => AssembledTransaction<string>;
+        create_account_for_key: (json: string) => AssembledTransaction<string>;
+        create_account_sponsored: (json: string) => AssembledTransaction<string>;
+        deposit_gift: (json: string) => AssembledTransaction<null>;
+        claim_gift: (json: string) => AssembledTransaction<string>;
+        gift_claim_payload: (json: string) => AssembledTransaction<Buffer>;
+        refund_gift: (json: string) => AssembledTransaction<null>;
+        get_gift: (json: string) => AssembledTransaction<Option<Gift>>;
+        get_account_indices: (json: string) => AssembledTransaction<Array<u32>>;
+        lookup_by_key: (json: string) => AssembledTransaction<Array<string>>;
+        lookup_by_credential: (json: string) => AssembledTransaction<Array<string>>;
+        register_signer: (json: string) => AssembledTransaction<null>;
+        unregister_signer: (json: string) => AssembledTransaction<null>;
+        set_account_wasm: (json: string) => AssembledTransaction<u32>;
+        set_verifier_wasm: (json: string) => AssembledTransaction<u32>;
+        set_fee: (json: string) => AssembledTransaction<null>;
+        set_fee_exempt: (json: string) => AssembledTransaction<null>;
+        set_guardian: (json: string) => AssembledTransaction<null>;
+        pause: (json: string) => AssembledTransaction<null>;
+        unpause: (json: string) => AssembledTransaction<null>;
+        is_paused: (json: string) => AssembledTransaction<boolean>;
+        get_guardian: (json: string) => AssembledTransaction<Option<string>>;
+        get_fee: (json: string) => AssembledTransaction<FeeConfig>;
+        get_treasury: (json: string) => AssembledTransaction<Option<string>>;
+        is_fee_exempt: (json: string) => AssembledTransaction<boolean>;
+        funding_token: (json: string) => AssembledTransaction<string>;
+        get_admin: (json: string) => AssembledTransaction<string>;
+        get_account_wasm: (json: string) => AssembledTransaction<Buffer>;
+        get_account_wasm_version: (json: string) => AssembledTransaction<u32>;
+        get_account_wasm_at: (json: string) => AssembledTransaction<Option<Buffer>>;
+        is_approved_account_wasm: (json: string) => AssembledTransaction<boolean>;
+        get_verifier_wasm: (json: string) => AssembledTransaction<Buffer>;
+        get_verifier_wasm_version: (json: string) => AssembledTransaction<u32>;
+        get_verifier_wasm_at: (json: string) => AssembledTransaction<Option<Buffer>>;
+        register_verifier: (json: string) => AssembledTransaction<null>;
+        remove_verifier: (json: string) => AssembledTransaction<null>;
+        get_verifier_kinds: (json: string) => AssembledTransaction<Array<string>>;
+        get_verifier_address: (json: string) => AssembledTransaction<string>;
+        is_verifier_deployed: (json: string) => AssembledTransaction<boolean>;
+        ensure_verifier: (json: string) => AssembledTransaction<string>;
+        get_verifier_kind_wasm: (json: string) => AssembledTransaction<Option<Buffer>>;
     };
 }
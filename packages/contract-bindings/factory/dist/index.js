@@ -180,18 +180,148 @@ export const WebAuthnError = {
      */
     3118: { message: "BackupEligibilityAndStateNotSet" },
 };
+export const FactoryError = {
+    /**
+     * An account already exists at the requested address, but the supplied
+     * key is not a signer on its default context rule.
+     */
+    1: { message: "SignerMismatch" },
+    /**
+     * An account already exists at the requested address and the entrypoint
+     * cannot top it up.
+     */
+    2: { message: "AccountExists" },
+    /**
+     * An account configuration has no signers on its default rule.
+     */
+    3: { message: "NoSigners" },
+    /**
+     * A gift escrow is already open under this claim key.
+     */
+    4: { message: "GiftExists" },
+    /**
+     * No gift escrow is open under this claim key.
+     */
+    5: { message: "GiftNotFound" },
+    /**
+     * The gift's expiry ledger has passed, or would already have passed
+     * when depositing.
+     */
+    6: { message: "GiftExpired" },
+    /**
+     * The gift can still be claimed, so it cannot be refunded yet.
+     */
+    7: { message: "GiftNotExpired" },
+    /**
+     * A batch has more entries than one call may deploy.
+     */
+    8: { message: "BatchTooLarge" },
+    /**
+     * A flat fee is negative or a basis-point fee exceeds 10 000.
+     */
+    9: { message: "InvalidFee" },
+    /**
+     * The caller is neither the admin nor the guardian.
+     */
+    10: { message: "Unauthorized" },
+    /**
+     * The factory is paused and does not create or fund accounts.
+     */
+    11: { message: "Paused" },
+    /**
+     * A signer spec names a verifier kind the factory has no WASM for.
+     */
+    12: { message: "UnknownVerifier" },
+    /**
+     * The signer hooks were called by an account this factory did not
+     * deploy.
+     */
+    13: { message: "UnknownAccount" },
+    /**
+     * The key data to index is not a signer on the account's default rules.
+     */
+    14: { message: "SignerNotFound" },
+    /**
+     * The key data to drop from the index is still a signer on the account.
+     */
+    15: { message: "SignerStillActive" },
+    /**
+     * A gift amount is zero or negative.
+     */
+    16: { message: "InvalidAmount" },
+};
 export class Client extends ContractClient {
     options;
     static async deploy(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    { admin, account_wasm, verifier_wasm, funding_token },
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options) {
-        return ContractClient.deploy(null, options);
+        return ContractClient.deploy({ admin, account_wasm, verifier_wasm, funding_token }, options);
     }
     constructor(options) {
         super(new ContractSpec([
-            "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAAAAAAA",
-            "AAAAAAAAAGVEZXBsb3kgYW4gYWNjb3VudCBjb250cmFjdCBhbmQgYWRkIGEgcGFzc2tleSB0byBpdC4gTGFzdGx5IHRyYW5zZmVyIGZ1bmRzIHRvIHRoZSBjb250cmFjdCdzIGFjY291bnQuCgAAAAAAAA5jcmVhdGVfYWNjb3VudAAAAAAAAwAAAAAAAAAGZnVuZGVyAAAAAAATAAAAAAAAAANrZXkAAAAD7gAAAEEAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAAT",
-            "AAAAAAAAAAAAAAANZ2V0X2NfYWRkcmVzcwAAAAAAAAEAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAEAAAAT",
+            "AAAAAAAAAPJJbml0aWFsaXplIHRoZSBmYWN0b3J5IHdpdGggYW4gYWRtaW4gYW5kIHRoZSBpbml0aWFsIGFjY291bnQgYW5kCnZlcmlmaWVyIFdBU00gaGFzaGVzIChib3RoIHJlY29yZGVkIGFzIHZlcnNpb24gMSkuCgpgZnVuZGluZ190b2tlbmAgaXMgdGhlIFNFUC00MSB0b2tlbiB0aGF0IGBhbW91bnRgcyBhbmQgZmVlcyBhcmUgcGFpZAppbi4gYE5vbmVgIHVzZXMgdGhlIG5ldHdvcmsncyBuYXRpdmUgWExNIGFzc2V0IGNvbnRyYWN0LgAAAAAADV9fY29uc3RydWN0b3IAAAAAAAAEAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAADGFjY291bnRfd2FzbQAAA+4AAAAgAAAAAAAAAA12ZXJpZmllcl93YXNtAAAAAAAD7gAAACAAAAAAAAAADWZ1bmRpbmdfdG9rZW4AAAAAAAPoAAAAEwAAAAA=",
+            "AAAAAAAAAklEZXBsb3kgYW4gYWNjb3VudCBjb250cmFjdCBhbmQgYWRkIGEgcGFzc2tleSB0byBpdC4gTGFzdGx5IHRyYW5zZmVyIGZ1bmRzIHRvIHRoZSBjb250cmFjdCdzIGFjY291bnQuCgpJZiB0aGUgYWNjb3VudCBhbHJlYWR5IGV4aXN0cyB0aGlzIG9ubHkgdG9wcyBpdCB1cCwgcHJvdmlkZWQgYGtleWAgaXMgYQpzaWduZXIgb24gaXRzIGRlZmF1bHQgY29udGV4dCBydWxlLgoKYGluZGV4YCBzZWxlY3RzIHdoaWNoIG9mIHRoZSBmdW5kZXIncyBhY2NvdW50cyB0byBjcmVhdGUsIHNvIG9uZQpHLWFkZHJlc3MgY2FuIG93biBzZXZlcmFsIEMtYWRkcmVzc2VzLiBgTm9uZWAgaXMgaW5kZXggMCwgd2hpY2gga2VlcHMKdGhlIGFkZHJlc3MgdGhlIGZhY3RvcnkgaGFzIGFsd2F5cyBkZXJpdmVkIGZvciBhIGZ1bmRlci4KCmBjcmVkZW50aWFsX2lkYCBpcyB0aGUgV2ViQXV0aG4gY3JlZGVudGlhbCBJRCBvZiB0aGUgcGFzc2tleS4gSXQgaXMKc3RvcmVkIGFmdGVyIHRoZSBwdWJsaWMga2V5IGluIHRoZSBzaWduZXIncyBrZXkgZGF0YSBzbyBjbGllbnRzIGNhbgpidWlsZCBgYWxsb3dDcmVkZW50aWFsc2AgZnJvbSBvbi1jaGFpbiBzdGF0ZS4AAAAAAAAOY3JlYXRlX2FjY291bnQAAAAAAAUAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAAAAAADa2V5AAAAA+4AAABBAAAAAAAAAA1jcmVkZW50aWFsX2lkAAAAAAAD6AAAAA4AAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAFaW5kZXgAAAAAAAPoAAAABAAAAAEAAAAT",
+            "AAAAAAAAAMhTYW1lIGFzIGBjcmVhdGVfYWNjb3VudGAsIGJ1dCBmdW5kcyB0aGUgYWNjb3VudCB3aXRoIGFueSBudW1iZXIgb2YKU0VQLTQxIHRva2VucyAoZS5nLiBYTE0sIFVTREMsIEVVUkMgU0FDcykgaW4gYSBzaW5nbGUgdHJhbnNhY3Rpb24uCkVhY2ggYCh0b2tlbiwgYW1vdW50KWAgcGFpciBpcyB0cmFuc2ZlcnJlZCBmcm9tIGBmdW5kZXJgIGluIG9yZGVyLgAAABpjcmVhdGVfYWNjb3VudF93aXRoX2Fzc2V0cwAAAAAABQAAAAAAAAAGZnVuZGVyAAAAAAATAAAAAAAAAANrZXkAAAAD7gAAAEEAAAAAAAAADWNyZWRlbnRpYWxfaWQAAAAAAAPoAAAADgAAAAAAAAAGYXNzZXRzAAAAAAPqAAAD7QAAAAIAAAATAAAACwAAAAAAAAAFaW5kZXgAAAAAAAPoAAAABAAAAAEAAAAT",
+            "AAAAAAAAActEZXBsb3kgYW4gYWNjb3VudCB3aXRoIHNldmVyYWwgaW5pdGlhbCBzaWduZXJzLCBwb2xpY2llcyBhbmQgZXh0cmEKY29udGV4dCBydWxlcyBpbiBvbmUgY2FsbCwgdGhlbiBmdW5kIGl0IHdpdGggYGFtb3VudGAgb2YgdGhlIGZ1bmRpbmcKdG9rZW4uIFNpZ25lcnMgYXJlIGdpdmVuIGFzIGBTaWduZXJTcGVjYHMgKHBhc3NrZXksIEVkMjU1MTkga2V5LApkZWxlZ2F0ZWQgYWRkcmVzcyBvciBhbm90aGVyIHJlZ2lzdGVyZWQgdmVyaWZpZXIga2luZCk7IGBFeHRlcm5hbGAKc3BlY3MgbXVzdCBuYW1lIGEga2luZCByZWdpc3RlcmVkIHdpdGggYHJlZ2lzdGVyX3ZlcmlmaWVyYC4gVW5saWtlCmBjcmVhdGVfYWNjb3VudGAsIHRoaXMgZmFpbHMgaWYgdGhlIGFjY291bnQgYXQgYGluZGV4YCBhbHJlYWR5IGV4aXN0cywKc2luY2UgYW4gZXhpc3RpbmcgY29uZmlndXJhdGlvbiBjYW5ub3QgYmUgcmVjb25jaWxlZC4AAAAAGmNyZWF0ZV9hY2NvdW50X3dpdGhfY29uZmlnAAAAAAAEAAAAAAAAAAZmdW5kZXIAAAAAABMAAAAAAAAABmNvbmZpZwAAAAAH0AAAAA1BY2NvdW50Q29uZmlnAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABWluZGV4AAAAAAAD6AAAAAQAAAABAAAAEw==",
+            "AAAAAAAAAQ5EZXBsb3kgYW5kIGZ1bmQgc2V2ZXJhbCBvZiB0aGUgZnVuZGVyJ3MgYWNjb3VudHMgaW4gb25lIGNhbGwuIEVhY2gKZW50cnkgaXMgYChrZXksIGNyZWRlbnRpYWxfaWQsIGFtb3VudCwgaW5kZXgpYCBhbmQgYmVoYXZlcyBsaWtlCmBjcmVhdGVfYWNjb3VudGAgd2l0aCB0aG9zZSBhcmd1bWVudHMsIGluY2x1ZGluZyB0b3BwaW5nIHVwIGFjY291bnRzCnRoYXQgYWxyZWFkeSBleGlzdC4gQXQgbW9zdCBgTUFYX0JBVENIX1NJWkVgICgzKSBlbnRyaWVzIGFyZSBhY2NlcHRlZC4AAAAAAA9jcmVhdGVfYWNjb3VudHMAAAAAAgAAAAAAAAAGZnVuZGVyAAAAAAATAAAAAAAAAAdlbnRyaWVzAAAAA+oAAAPtAAAABAAAA+4AAABBAAAD6AAAAA4AAAALAAAABAAAAAEAAAPqAAAAEw==",
+            "AAAAAAAAAAAAAAANZ2V0X2NfYWRkcmVzcwAAAAAAAAIAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAAAAAAFaW5kZXgAAAAAAAPoAAAABAAAAAEAAAAT",
+            "AAAAAAAAANZQcmVkaWN0IHRoZSBDLWFkZHJlc3Mgb2YgdGhlIGFjY291bnQgb3duZWQgYnkgYGtleWAuIFRoZSBhZGRyZXNzIG9ubHkKZGVwZW5kcyBvbiB0aGUgZmFjdG9yeSwgdGhlIHBhc3NrZXkgYW5kIGl0cyBjcmVkZW50aWFsIElELCBzbyBpdCBjYW4KYmUgc2hhcmVkIGFuZCBmdW5kZWQgYmVmb3JlIHRoZSB1c2VyIGhhcyBhIEctYWRkcmVzcyBvciB0aGUgYWNjb3VudApleGlzdHMuAAAAAAAPcHJlZGljdF9hZGRyZXNzAAAAAAIAAAAAAAAAA2tleQAAAAPuAAAAQQAAAAAAAAANY3JlZGVudGlhbF9pZAAAAAAAA+gAAAAOAAAAAQAAABM=",
+            "AAAAAAAAANdEZXBsb3kgdGhlIGFjY291bnQgYXQgYHByZWRpY3RfYWRkcmVzcyhrZXksIGNyZWRlbnRpYWxfaWQpYCBpZiBpdCBkb2VzCm5vdCBleGlzdCB5ZXQsIHRoZW4gdHJhbnNmZXIgYGFtb3VudGAgZnJvbSBgZnVuZGVyYCBpbnRvIGl0LiBBbnkgZnVuZGVyCmNhbiBkbyB0aGlzLCBzaW5jZSB0aGUgYWRkcmVzcyBjb21taXRzIHRvIHRoZSBzaWduZXIgaXQgaXMgY3JlYXRlZAp3aXRoLgAAAAAWY3JlYXRlX2FjY291bnRfZm9yX2tleQAAAAAABAAAAAAAAAADa2V5AAAAA+4AAABBAAAAAAAAAA1jcmVkZW50aWFsX2lkAAAAAAAD6AAAAA4AAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAABM=",
+            "AAAAAAAAAPdTcG9uc29yZWQgdmFyaWFudCBvZiBgY3JlYXRlX2FjY291bnRfZm9yX2tleWAuIGByZWxheWVyYCBzdWJtaXRzIHRoZQp0cmFuc2FjdGlvbiBhbmQgcGF5cyBpdHMgZmVlczsgYGZ1bmRlcmAgb25seSBzaWducyBhbiBhdXRob3JpemF0aW9uCmJvdW5kIHRvIGAoa2V5LCBjcmVkZW50aWFsX2lkLCBhbW91bnQpYCwgc28gdGhlIHJlbGF5ZXIgY2Fubm90CnJlZGlyZWN0IHRoZSBmdW5kcyBvciBjaGFuZ2UgaG93IG11Y2ggaXMgbW92ZWQuAAAAABhjcmVhdGVfYWNjb3VudF9zcG9uc29yZWQAAAAFAAAAAAAAAAdyZWxheWVyAAAAABMAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAAAAAADa2V5AAAAA+4AAABBAAAAAAAAAA1jcmVkZW50aWFsX2lkAAAAAAAD6AAAAA4AAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAAT",
+            "AAAAAAAAATZIb2xkIGBhbW91bnRgIG9mIGB0b2tlbmAgaW4gZXNjcm93IHVudGlsIGl0IGlzIGNsYWltZWQgd2l0aCBhCnNpZ25hdHVyZSBmcm9tIHRoZSBFZDI1NTE5IGBjbGFpbV9rZXlgLiBUaGUgZ2lmdCBjYW4gYmUgY2xhaW1lZCB1cCB0bwphbmQgaW5jbHVkaW5nIGBleHBpcnlfbGVkZ2VyYDsgYWZ0ZXIgdGhhdCBvbmx5IGByZWZ1bmRfZ2lmdGAgY2FuCnJlbGVhc2UgaXQuIFRoZSBzZW5kZXIgYWxzbyBlc2Nyb3dzIHRoZSBhY2NvdW50IGZlZSwgaW4gdGhlIGZ1bmRpbmcKdG9rZW4sIHNpbmNlIHRoZSBjbGFpbSBtYXkgZGVwbG95IGFuIGFjY291bnQuAAAAAAAMZGVwb3NpdF9naWZ0AAAABQAAAAAAAAAGc2VuZGVyAAAAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAJY2xhaW1fa2V5AAAAAAAD7gAAACAAAAAAAAAADWV4cGlyeV9sZWRnZXIAAAAAAAAEAAAAAA==",
+            "AAAAAAAAAYlEZXBsb3kgdGhlIGFjY291bnQgYXQgYHByZWRpY3RfYWRkcmVzcyhrZXksIGNyZWRlbnRpYWxfaWQpYCBpZiBuZWVkZWQKYW5kIG1vdmUgdGhlIGdpZnQgaGVsZCB1bmRlciBgY2xhaW1fa2V5YCBpbnRvIGl0LiBObyBhdXRoIGlzCnJlcXVpcmVkOiBgc2lnbmF0dXJlYCBpcyB0aGUgY2xhaW0ga2V5J3Mgc2lnbmF0dXJlIG92ZXIKYGdpZnRfY2xhaW1fcGF5bG9hZChjbGFpbV9rZXksIGtleSwgY3JlZGVudGlhbF9pZClgLCB3aGljaCBiaW5kcyB0aGUKZ2lmdCB0byB0aGF0IGFjY291bnQgKHNlZSB0aGUgYGdpZnRgIG1vZHVsZSBkb2NzKS4gVGhlIGVzY3Jvd2VkIGZlZQpnb2VzIHRvIHRoZSB0cmVhc3VyeSBpZiB0aGlzIGRlcGxveXMgdGhlIGFjY291bnQsIGVsc2UgdG8gdGhlIHNlbmRlci4AAAAAAAAKY2xhaW1fZ2lmdAAAAAAABAAAAAAAAAAJY2xhaW1fa2V5AAAAAAAD7gAAACAAAAAAAAAAA2tleQAAAAPuAAAAQQAAAAAAAAANY3JlZGVudGlhbF9pZAAAAAAAA+gAAAAOAAAAAAAAAAlzaWduYXR1cmUAAAAAAAPuAAAAQAAAAAEAAAAT",
+            "AAAAAAAAAHBUaGUgbWVzc2FnZSBgY2xhaW1fa2V5YCBtdXN0IHNpZ24gZm9yIGBjbGFpbV9naWZ0YCB0byBzZW5kIGl0cyBnaWZ0CnRvIHRoZSBhY2NvdW50IG9mIGBrZXlgIGFuZCBgY3JlZGVudGlhbF9pZGAuAAAAEmdpZnRfY2xhaW1fcGF5bG9hZAAAAAAAAwAAAAAAAAAJY2xhaW1fa2V5AAAAAAAD7gAAACAAAAAAAAAAA2tleQAAAAPuAAAAQQAAAAAAAAANY3JlZGVudGlhbF9pZAAAAAAAA+gAAAAOAAAAAQAAAA4=",
+            "AAAAAAAAAItSZXR1cm4gYW4gZXhwaXJlZCwgdW5jbGFpbWVkIGdpZnQgYW5kIGl0cyBlc2Nyb3dlZCBmZWUgdG8gdGhlIHNlbmRlci4KQW55b25lIG1heSBjYWxsIHRoaXMsIHNpbmNlIHRoZSBmdW5kcyBjYW4gb25seSBnbyBiYWNrIHRvIHRoZSBzZW5kZXIuAAAAAAtyZWZ1bmRfZ2lmdAAAAAABAAAAAAAAAAljbGFpbV9rZXkAAAAAAAPuAAAAIAAAAAA=",
+            "AAAAAAAAAC9UaGUgb3BlbiBnaWZ0IGVzY3JvdyB1bmRlciBgY2xhaW1fa2V5YCwgaWYgYW55LgAAAAAIZ2V0X2dpZnQAAAABAAAAAAAAAAljbGFpbV9rZXkAAAAAAAPuAAAAIAAAAAEAAAPoAAAH0AAAAARHaWZ0",
+            "AAAAAAAAADpJbmRpY2VzIHRoZSBmdW5kZXIgaGFzIGFscmVhZHkgdXNlZCB3aXRoIGBjcmVhdGVfYWNjb3VudGAuAAAAAAATZ2V0X2FjY291bnRfaW5kaWNlcwAAAAABAAAAAAAAAAZmdW5kZXIAAAAAABMAAAABAAAD6gAAAAQ=",
+            "AAAAAAAAAMRBY2NvdW50cyB0aGF0IHdlcmUgY3JlYXRlZCB3aXRoLCBvciBoYXZlIHJlZ2lzdGVyZWQsIHRoaXMgcGFzc2tleQpwdWJsaWMga2V5IGFzIGEgc2lnbmVyLiBSZXR1cm5zIHVwIHRvIGBsaW1pdGAgKGF0IG1vc3QgNTApIGFjY291bnRzCmZyb20gcG9zaXRpb24gYHN0YXJ0YDsgYSBzaG9ydGVyIHBhZ2UgbWVhbnMgdGhlcmUgYXJlIG5vIG1vcmUuAAAADWxvb2t1cF9ieV9rZXkAAAAAAAADAAAAAAAAAANrZXkAAAAD7gAAAEEAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAAT",
+            "AAAAAAAAAH9BY2NvdW50cyB0aGF0IHdlcmUgY3JlYXRlZCB3aXRoLCBvciBoYXZlIHJlZ2lzdGVyZWQsIGEgc2lnbmVyIHdpdGgKdGhpcyBXZWJBdXRobiBjcmVkZW50aWFsIElELiBQYWdpbmF0ZWQgbGlrZSBgbG9va3VwX2J5X2tleWAuAAAAABRsb29rdXBfYnlfY3JlZGVudGlhbAAAAAMAAAAAAAAADWNyZWRlbnRpYWxfaWQAAAAAAAAOAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAAEw==",
+            "AAAAAAAAARVIb29rIGZvciBhbiBhY2NvdW50IHRvIGluZGV4IGEgc2lnbmVyIGl0IGFkZGVkLiBga2V5X2RhdGFgIGlzIHRoZQpgU2lnbmVyOjpFeHRlcm5hbGAga2V5IGRhdGE6IGEgNjUtYnl0ZSBwdWJsaWMga2V5IG9wdGlvbmFsbHkgZm9sbG93ZWQKYnkgdGhlIGNyZWRlbnRpYWwgSUQuIE9ubHkgYWNjb3VudHMgdGhpcyBmYWN0b3J5IGRlcGxveWVkIG1heSBjYWxsIGl0LAphbmQgYGtleV9kYXRhYCBtdXN0IGJlIGEgc2lnbmVyIG9uIG9uZSBvZiB0aGVpciBkZWZhdWx0IGNvbnRleHQgcnVsZXMuAAAAAAAAD3JlZ2lzdGVyX3NpZ25lcgAAAAACAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAACGtleV9kYXRhAAAADgAAAAA=",
+            "AAAAAAAAAINIb29rIGZvciBhbiBhY2NvdW50IHRvIGRyb3AgYSBzaWduZXIgaXQgcmVtb3ZlZCBmcm9tIHRoZSBpbmRleC4gRmFpbHMKd2hpbGUgYGtleV9kYXRhYCBpcyBzdGlsbCBhIHNpZ25lciBvbiBhIGRlZmF1bHQgY29udGV4dCBydWxlLgAAAAARdW5yZWdpc3Rlcl9zaWduZXIAAAAAAAACAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAACGtleV9kYXRhAAAADgAAAAA=",
+            "AAAAAAAAAINSZXBsYWNlIHRoZSBzbWFydC1hY2NvdW50IFdBU00gdXNlZCBmb3IgbmV3IGRlcGxveW1lbnRzLiBBY2NvdW50cwphbHJlYWR5IGRlcGxveWVkIGtlZXAgdGhlaXIgY29kZS4gUmV0dXJucyB0aGUgbmV3IHZlcnNpb24gbnVtYmVyLgAAAAAQc2V0X2FjY291bnRfd2FzbQAAAAEAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAgAAAAAQAAAAQ=",
+            "AAAAAAAAALJSZXBsYWNlIHRoZSBXZWJBdXRobiB2ZXJpZmllciBXQVNNIGFuZCBkZXBsb3kgdGhlIG5ldyB2ZXJpZmllci4gSXQKbGl2ZXMgYXQgYSBuZXcgYWRkcmVzczsgYWNjb3VudHMgZGVwbG95ZWQgZWFybGllciBrZWVwIHBvaW50aW5nIGF0CnRoZSBvbGQgb25lLiBSZXR1cm5zIHRoZSBuZXcgdmVyc2lvbiBudW1iZXIuAAAAAAARc2V0X3ZlcmlmaWVyX3dhc20AAAAAAAABAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAAE",
+            "AAAAAAAAAOpTZXQgdGhlIGZlZSBjaGFyZ2VkIGZvciBlYWNoIGFjY291bnQgdGhlIGZhY3RvcnkgZGVwbG95cyBhbmQgdGhlCnRyZWFzdXJ5IGl0IGlzIHBhaWQgdG8uIFRoZSBmZWUgaXMgcGFpZCBpbiB0aGUgZnVuZGluZyB0b2tlbiBieSB0aGUKZnVuZGVyIG9uIHRvcCBvZiBgYW1vdW50YDsgdG9wLXVwcyBvZiBleGlzdGluZyBhY2NvdW50cyBhcmUgZnJlZS4KYEZlZUNvbmZpZzo6RGlzYWJsZWRgIHR1cm5zIGl0IG9mZi4AAAAAAAdzZXRfZmVlAAAAAAIAAAAAAAAAA2ZlZQAAAAfQAAAACUZlZUNvbmZpZwAAAAAAAAAAAAAIdHJlYXN1cnkAAAATAAAAAA==",
+            "AAAAAAAAADNFeGVtcHQgYGZ1bmRlcmAgZnJvbSB0aGUgZmVlLCBvciBtYWtlIGl0IHBheSBhZ2Fpbi4AAAAADnNldF9mZWVfZXhlbXB0AAAAAAACAAAAAAAAAAZmdW5kZXIAAAAAABMAAAAAAAAABmV4ZW1wdAAAAAAAAQAAAAA=",
+            "AAAAAAAAAIFTZXQgb3IgY2xlYXIgdGhlIGd1YXJkaWFuLCBhIHNlY29uZCBhZGRyZXNzIChlLmcuIGFuIG9wcyBrZXkgb3IKbW9uaXRvcmluZyBzZXJ2aWNlKSB0aGF0IGNhbiBwYXVzZSB0aGUgZmFjdG9yeSB3aXRob3V0IHRoZSBhZG1pbi4AAAAAAAAMc2V0X2d1YXJkaWFuAAAAAQAAAAAAAAAIZ3VhcmRpYW4AAAPoAAAAEwAAAAA=",
+            "AAAAAAAAATtTdG9wIGFsbCBhY2NvdW50IGNyZWF0aW9uLCBlLmcuIHdoZW4gdGhlIGFjY291bnQgV0FTTSBoYXMgYSBrbm93bgp2dWxuZXJhYmlsaXR5LiBFdmVyeSBgY3JlYXRlX2FjY291bnQqYCBlbnRyeXBvaW50ICh0b3AtdXBzIG9mIGV4aXN0aW5nCmFjY291bnRzIGluY2x1ZGVkKSwgYGNsYWltX2dpZnRgIGFuZCBgZW5zdXJlX3ZlcmlmaWVyYCBmYWlsIHdpdGgKYFBhdXNlZGA7IGdpZnQgZGVwb3NpdHMgYW5kIHJlZnVuZHMgYW5kIGFkZHJlc3MgbG9va3VwcyBrZWVwIHdvcmtpbmcuCmBjYWxsZXJgIG11c3QgYmUgdGhlIGFkbWluIG9yIHRoZSBndWFyZGlhbi4AAAAABXBhdXNlAAAAAAAAAQAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAA==",
+            "AAAAAAAAAIRSZXN1bWUgYWNjb3VudCBjcmVhdGlvbi4gT25seSB0aGUgYWRtaW4gY2FuIHVucGF1c2UsIHNvIGEgZ3VhcmRpYW4ga2V5IGNhbgpzdG9wIHRoZSBmYWN0b3J5IHF1aWNrbHkgYnV0IG5vdCBsaWZ0IGEgcGF1c2Ugb24gaXRzIG93bi4AAAAHdW5wYXVzZQAAAAAAAAAAAA==",
+            "AAAAAAAAAAAAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAAB",
+            "AAAAAAAAAAAAAAAMZ2V0X2d1YXJkaWFuAAAAAAAAAAEAAAPoAAAAEw==",
+            "AAAAAAAAAAAAAAAHZ2V0X2ZlZQAAAAAAAAAAAQAAB9AAAAAJRmVlQ29uZmlnAAAA",
+            "AAAAAAAAAAAAAAAMZ2V0X3RyZWFzdXJ5AAAAAAAAAAEAAAPoAAAAEw==",
+            "AAAAAAAAAAAAAAANaXNfZmVlX2V4ZW1wdAAAAAAAAAEAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAEAAAAB",
+            "AAAAAAAAACNUaGUgdG9rZW4gYWNjb3VudHMgYXJlIGZ1bmRlZCB3aXRoLgAAAAANZnVuZGluZ190b2tlbgAAAAAAAAAAAAABAAAAEw==",
+            "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+            "AAAAAAAAAAAAAAAQZ2V0X2FjY291bnRfd2FzbQAAAAAAAAABAAAD7gAAACA=",
+            "AAAAAAAAAAAAAAAYZ2V0X2FjY291bnRfd2FzbV92ZXJzaW9uAAAAAAAAAAEAAAAE",
+            "AAAAAAAAAEVMb29rIHVwIHRoZSBhY2NvdW50IFdBU00gaGFzaCByZWNvcmRlZCBmb3IgYSBwYXN0IG9yIGN1cnJlbnQgdmVyc2lvbi4AAAAAAAATZ2V0X2FjY291bnRfd2FzbV9hdAAAAAABAAAAAAAAAAd2ZXJzaW9uAAAAAAQAAAABAAAD6AAAA+4AAAAg",
+            "AAAAAAAAAMdXaGV0aGVyIGFjY291bnRzIHRoYXQgbmFtZSB0aGUgZmFjdG9yeSBhcyB0aGVpciB1cGdyYWRlIGF1dGhvcml0eSBtYXkKaW5zdGFsbCBgd2FzbV9oYXNoYC4gT25seSB0aGUgY3VycmVudCBhY2NvdW50IFdBU00gaXMgYXBwcm92ZWQsIHNvCmFjY291bnRzIGNhbm5vdCBtb3ZlIGJhY2sgdG8gYSB2ZXJzaW9uIHRoZSBhZG1pbiBoYXMgcmVwbGFjZWQuAAAAABhpc19hcHByb3ZlZF9hY2NvdW50X3dhc20AAAABAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAAB",
+            "AAAAAAAAAAAAAAARZ2V0X3ZlcmlmaWVyX3dhc20AAAAAAAAAAAAAAQAAA+4AAAAg",
+            "AAAAAAAAAAAAAAAZZ2V0X3ZlcmlmaWVyX3dhc21fdmVyc2lvbgAAAAAAAAAAAAABAAAABA==",
+            "AAAAAAAAAEZMb29rIHVwIHRoZSB2ZXJpZmllciBXQVNNIGhhc2ggcmVjb3JkZWQgZm9yIGEgcGFzdCBvciBjdXJyZW50IHZlcnNpb24uAAAAAAAUZ2V0X3ZlcmlmaWVyX3dhc21fYXQAAAABAAAAAAAAAAd2ZXJzaW9uAAAAAAQAAAABAAAD6AAAA+4AAAAg",
+            "AAAAAAAAAK1BbGxvdyBgU2lnbmVyU3BlY2BzIG9mIGBraW5kYCBhbmQgdmVyaWZ5IHRoZW0gd2l0aCBhIHNoYXJlZCBpbnN0YW5jZQpvZiBgd2FzbV9oYXNoYCwgd2hpY2ggaXMgZGVwbG95ZWQgcmlnaHQgYXdheS4gUmVnaXN0ZXJpbmcgYHdlYmF1dGhuYAppcyB0aGUgc2FtZSBhcyBgc2V0X3ZlcmlmaWVyX3dhc21gLgAAAAAAABFyZWdpc3Rlcl92ZXJpZmllcgAAAAAAAAIAAAAAAAAABGtpbmQAAAARAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+            "AAAAAAAAAJZTdG9wIGFjY2VwdGluZyBzaWduZXJzIG9mIGBraW5kYCBmb3IgbmV3IGFjY291bnRzLiBBY2NvdW50cyBhbHJlYWR5CnVzaW5nIHRoZSB2ZXJpZmllciBhcmUgdW5hZmZlY3RlZC4gVGhlIGJ1aWx0LWluIGB3ZWJhdXRobmAga2luZCBjYW5ub3QKYmUgcmVtb3ZlZC4AAAAAAA9yZW1vdmVfdmVyaWZpZXIAAAAAAQAAAAAAAAAEa2luZAAAABEAAAAA",
+            "AAAAAAAAAEFFdmVyeSBzaWduZXIga2luZCBuZXcgYWNjb3VudHMgY2FuIHVzZSwgc3RhcnRpbmcgd2l0aCBgd2ViYXV0aG5gLgAAAAAAABJnZXRfdmVyaWZpZXJfa2luZHMAAAAAAAAAAAABAAAD6gAAABE=",
+            "AAAAAAAAAKBBZGRyZXNzIG9mIHRoZSBzaGFyZWQgdmVyaWZpZXIgZm9yIGBraW5kYCwgd2hldGhlciBvciBub3QgaXQgaGFzIGJlZW4KZGVwbG95ZWQgeWV0LiBDbGllbnRzIGNhbiB1c2UgaXQgdG8gYnVpbGQgYFNpZ25lcjo6RXh0ZXJuYWxgIHZhbHVlcwp3aXRob3V0IGEgdHJhbnNhY3Rpb24uAAAAFGdldF92ZXJpZmllcl9hZGRyZXNzAAAAAQAAAAAAAAAEa2luZAAAABEAAAABAAAAEw==",
+            "AAAAAAAAADlXaGV0aGVyIHRoZSBzaGFyZWQgdmVyaWZpZXIgZm9yIGBraW5kYCBoYXMgYmVlbiBkZXBsb3llZC4AAAAAAAAUaXNfdmVyaWZpZXJfZGVwbG95ZWQAAAABAAAAAAAAAARraW5kAAAAEQAAAAEAAAAB",
+            "AAAAAAAAAMxEZXBsb3kgdGhlIHNoYXJlZCB2ZXJpZmllciBmb3IgYGtpbmRgIGlmIG5lZWRlZCBhbmQgcmV0dXJuIGl0cyBhZGRyZXNzLgpWZXJpZmllcnMgYXJlIGRlcGxveWVkIHdoZW4gcmVnaXN0ZXJlZCwgZXhjZXB0IHRoZSBXZWJBdXRobiB2ZXJpZmllcgpnaXZlbiB0byB0aGUgY29uc3RydWN0b3IsIHdoaWNoIGRlcGxveXMgd2l0aCB0aGUgZmlyc3QgYWNjb3VudC4AAAAPZW5zdXJlX3ZlcmlmaWVyAAAAAAEAAAAAAAAABGtpbmQAAAARAAAAAQAAABM=",
+            "AAAAAAAAADVUaGUgdmVyaWZpZXIgV0FTTSBoYXNoIHJlZ2lzdGVyZWQgZm9yIGBraW5kYCwgaWYgYW55LgAAAAAAABZnZXRfdmVyaWZpZXJfa2luZF93YXNtAAAAAAABAAAAAAAAAARraW5kAAAAEQAAAAEAAAPoAAAD7gAAACA=",
+            "AAAAAQAAAHNBbiBhZGRpdGlvbmFsIGNvbnRleHQgcnVsZSBmb3IgYSBuZXcgYWNjb3VudC4gTWlycm9ycyB0aGUgc21hcnQgYWNjb3VudCdzCmBDb250ZXh0UnVsZVBhcmFtc2AgY29uc3RydWN0b3IgYXJndW1lbnQuAAAAAAAAAAARQ29udGV4dFJ1bGVQYXJhbXMAAAAAAAAFAAAAAAAAAAxjb250ZXh0X3R5cGUAAAfQAAAAD0NvbnRleHRSdWxlVHlwZQAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAhwb2xpY2llcwAAA+wAAAATAAAAAAAAAAAAAAAHc2lnbmVycwAAAAPqAAAH0AAAAAZTaWduZXIAAAAAAAAAAAALdmFsaWRfdW50aWwAAAAD6AAAAAQ=",
+            "AAAAAgAAANdBIHNpZ25lciB0byBpbnN0YWxsIG9uIGEgbmV3IGFjY291bnQuIFRoZSBmYWN0b3J5IHJlc29sdmVzIGVhY2ggc3BlYyB0byBhCmBTaWduZXJgLCBwb2ludGluZyBgRXh0ZXJuYWxgIHNpZ25lcnMgYXQgdGhlIHZlcmlmaWVyIGl0IG1hbmFnZXMgZm9yIHRoZQpzcGVjJ3Mga2luZCwgc28gY2FsbGVycyBjYW5ub3QgYmluZCBhY2NvdW50cyB0byBhcmJpdHJhcnkgdmVyaWZpZXJzLgAAAAAAAAAAClNpZ25lclNwZWMAAAAAAAQAAAABAAAAREEgcGFzc2tleTogdW5jb21wcmVzc2VkIFAtMjU2IHB1YmxpYyBrZXkgYW5kIG9wdGlvbmFsIGNyZWRlbnRpYWwgSUQuAAAACFdlYkF1dGhuAAAAAgAAA+4AAABBAAAD6AAAAA4AAAABAAAAPEEgcmF3IEVkMjU1MTkgcHVibGljIGtleSwgY2hlY2tlZCBieSB0aGUgYGVkMjU1MTlgIHZlcmlmaWVyLgAAAAdFZDI1NTE5AAAAAAEAAAPuAAAAIAAAAAEAAABBQW4gZXhpc3RpbmcgU3RlbGxhciBhY2NvdW50IG9yIGNvbnRyYWN0IHRoYXQgYXV0aG9yaXplcyBuYXRpdmVseS4AAAAAAAAJRGVsZWdhdGVkAAAAAAAAAQAAABMAAAABAAAAQ0tleSBkYXRhIGZvciBhbnkgb3RoZXIgcmVnaXN0ZXJlZCB2ZXJpZmllciBraW5kIChlLmcuIGBzZWNwMjU2azFgKS4AAAAACEV4dGVybmFsAAAAAgAAABEAAAAO",
+            "AAAAAQAAAEJgQ29udGV4dFJ1bGVQYXJhbXNgIHdpdGggc2lnbmVyIHNwZWNzIGluc3RlYWQgb2YgcmVzb2x2ZWQgc2lnbmVycy4AAAAAAAAAAAAPQ29udGV4dFJ1bGVTcGVjAAAAAAUAAAAAAAAADGNvbnRleHRfdHlwZQAAB9AAAAAPQ29udGV4dFJ1bGVUeXBlAAAAAAAAAAAEbmFtZQAAABAAAAAAAAAACHBvbGljaWVzAAAD7AAAABMAAAAAAAAAAAAAAAdzaWduZXJzAAAAA+oAAAfQAAAAClNpZ25lclNwZWMAAAAAAAAAAAALdmFsaWRfdW50aWwAAAAD6AAAAAQ=",
+            "AAAAAQAAAI9GdWxsIGluaXRpYWwgY29uZmlndXJhdGlvbiBvZiBhIHNtYXJ0IGFjY291bnQ6IHRoZSBkZWZhdWx0IHJ1bGUncyBzaWduZXJzCmFuZCBwb2xpY2llcyAocG9saWN5IGFkZHJlc3MgdG8gaW5zdGFsbCBwYXJhbSksIHBsdXMgYW55IGV4dHJhIHJ1bGVzLgAAAAAAAAAADUFjY291bnRDb25maWcAAAAAAAADAAAAAAAAAA1jb250ZXh0X3J1bGVzAAAAAAAD6gAAB9AAAAAPQ29udGV4dFJ1bGVTcGVjAAAAAAAAAAAIcG9saWNpZXMAAAPsAAAAEwAAAAAAAAAAAAAAB3NpZ25lcnMAAAAD6gAAB9AAAAAKU2lnbmVyU3BlYwAA",
+            "AAAAAgAAAHpGZWUgdGhlIGZhY3RvcnkgY2hhcmdlcyBmdW5kZXJzIGZvciBlYWNoIGFjY291bnQgaXQgZGVwbG95cywgaW4gaXRzCmZ1bmRpbmcgdG9rZW4uIFRvcHBpbmcgdXAgYW4gZXhpc3RpbmcgYWNjb3VudCBpcyBmcmVlLgAAAAAAAAAAAAlGZWVDb25maWcAAAAAAAADAAAAAAAAAAAAAAAIRGlzYWJsZWQAAAABAAAAJEEgZml4ZWQgYW1vdW50IHBlciBkZXBsb3llZCBhY2NvdW50LgAAAARGbGF0AAAAAQAAAAsAAAABAAAAO0Jhc2lzIHBvaW50cyBvZiB0aGUgbmV3IGFjY291bnQncyBmdW5kZWQgYW1vdW50ICgxMDAgPSAxJSkuAAAAAANCcHMAAAAAAQAAAAQ=",
+            "AAAAAQAAAJNUb2tlbnMgaGVsZCBieSB0aGUgZmFjdG9yeSB1bnRpbCB0aGUgaG9sZGVyIG9mIHRoZSBjbGFpbSBrZXkgY3JlYXRlcwp0aGVpciBhY2NvdW50LCBvciB1bnRpbCBgZXhwaXJ5X2xlZGdlcmAgcGFzc2VzIGFuZCB0aGUgc2VuZGVyIHRha2VzIHRoZW0KYmFjay4AAAAAAAAAAARHaWZ0AAAABQAAAAAAAAAGYW1vdW50AAAAAAALAAAALUxhc3QgbGVkZ2VyIGF0IHdoaWNoIHRoZSBnaWZ0IGNhbiBiZSBjbGFpbWVkLgAAAAAAAA1leHBpcnlfbGVkZ2VyAAAAAAAABAAAAJpBY2NvdW50IGZlZSBwYWlkIGJ5IHRoZSBzZW5kZXIgaW4gdGhlIGZ1bmRpbmcgdG9rZW4gYXQgZGVwb3NpdC4gSXQKZ29lcyB0byB0aGUgdHJlYXN1cnkgaWYgdGhlIGNsYWltIGRlcGxveXMgdGhlIGFjY291bnQgYW5kIGJhY2sgdG8gdGhlCnNlbmRlciBvdGhlcndpc2UuAAAAAAADZmVlAAAAAAsAAAAAAAAABnNlbmRlcgAAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAAT",
+            "AAAAAgAAAAAAAAAAAAAABUVudHJ5AAAAAAAAAgAAAAEAAAAAAAAAA0tleQAAAAABAAAD7gAAACAAAAABAAAAAAAAAApDcmVkZW50aWFsAAAAAAABAAAD7gAAACA=",
+            "AAAAAQAAAAAAAAAAAAAABFNsb3QAAAACAAAAAAAAAAVlbnRyeQAAAAAAB9AAAAAFRW50cnkAAAAAAAAAAAAACHBvc2l0aW9uAAAABA==",
+            "AAAAAQAAAAAAAAAAAAAABk1lbWJlcgAAAAAAAgAAAAAAAAAHYWNjb3VudAAAAAATAAAAAAAAAAVlbnRyeQAAAAAAB9AAAAAFRW50cnkAAAA=",
+            "AAAABAAAAAAAAAAAAAAADEZhY3RvcnlFcnJvcgAAABAAAAB1QW4gYWNjb3VudCBhbHJlYWR5IGV4aXN0cyBhdCB0aGUgcmVxdWVzdGVkIGFkZHJlc3MsIGJ1dCB0aGUgc3VwcGxpZWQKa2V5IGlzIG5vdCBhIHNpZ25lciBvbiBpdHMgZGVmYXVsdCBjb250ZXh0IHJ1bGUuAAAAAAAADlNpZ25lck1pc21hdGNoAAAAAAABAAAAV0FuIGFjY291bnQgYWxyZWFkeSBleGlzdHMgYXQgdGhlIHJlcXVlc3RlZCBhZGRyZXNzIGFuZCB0aGUgZW50cnlwb2ludApjYW5ub3QgdG9wIGl0IHVwLgAAAAANQWNjb3VudEV4aXN0cwAAAAAAAAIAAAA8QW4gYWNjb3VudCBjb25maWd1cmF0aW9uIGhhcyBubyBzaWduZXJzIG9uIGl0cyBkZWZhdWx0IHJ1bGUuAAAACU5vU2lnbmVycwAAAAAAAAMAAAAzQSBnaWZ0IGVzY3JvdyBpcyBhbHJlYWR5IG9wZW4gdW5kZXIgdGhpcyBjbGFpbSBrZXkuAAAAAApHaWZ0RXhpc3RzAAAAAAAEAAAALE5vIGdpZnQgZXNjcm93IGlzIG9wZW4gdW5kZXIgdGhpcyBjbGFpbSBrZXkuAAAADEdpZnROb3RGb3VuZAAAAAUAAABSVGhlIGdpZnQncyBleHBpcnkgbGVkZ2VyIGhhcyBwYXNzZWQsIG9yIHdvdWxkIGFscmVhZHkgaGF2ZSBwYXNzZWQKd2hlbiBkZXBvc2l0aW5nLgAAAAAAC0dpZnRFeHBpcmVkAAAAAAYAAAA8VGhlIGdpZnQgY2FuIHN0aWxsIGJlIGNsYWltZWQsIHNvIGl0IGNhbm5vdCBiZSByZWZ1bmRlZCB5ZXQuAAAADkdpZnROb3RFeHBpcmVkAAAAAAAHAAAAMkEgYmF0Y2ggaGFzIG1vcmUgZW50cmllcyB0aGFuIG9uZSBjYWxsIG1heSBkZXBsb3kuAAAAAAANQmF0Y2hUb29MYXJnZQAAAAAAAAgAAAA7QSBmbGF0IGZlZSBpcyBuZWdhdGl2ZSBvciBhIGJhc2lzLXBvaW50IGZlZSBleGNlZWRzIDEwIDAwMC4AAAAACkludmFsaWRGZWUAAAAAAAkAAAAxVGhlIGNhbGxlciBpcyBuZWl0aGVyIHRoZSBhZG1pbiBub3IgdGhlIGd1YXJkaWFuLgAAAAAAAAxVbmF1dGhvcml6ZWQAAAAKAAAAO1RoZSBmYWN0b3J5IGlzIHBhdXNlZCBhbmQgZG9lcyBub3QgY3JlYXRlIG9yIGZ1bmQgYWNjb3VudHMuAAAAAAZQYXVzZWQAAAAAAAsAAABAQSBzaWduZXIgc3BlYyBuYW1lcyBhIHZlcmlmaWVyIGtpbmQgdGhlIGZhY3RvcnkgaGFzIG5vIFdBU00gZm9yLgAAAA9Vbmtub3duVmVyaWZpZXIAAAAADAAAAEdUaGUgc2lnbmVyIGhvb2tzIHdlcmUgY2FsbGVkIGJ5IGFuIGFjY291bnQgdGhpcyBmYWN0b3J5IGRpZCBub3QKZGVwbG95LgAAAAAOVW5rbm93bkFjY291bnQAAAAAAA0AAABFVGhlIGtleSBkYXRhIHRvIGluZGV4IGlzIG5vdCBhIHNpZ25lciBvbiB0aGUgYWNjb3VudCdzIGRlZmF1bHQgcnVsZXMuAAAAAAAADlNpZ25lck5vdEZvdW5kAAAAAAAOAAAARVRoZSBrZXkgZGF0YSB0byBkcm9wIGZyb20gdGhlIGluZGV4IGlzIHN0aWxsIGEgc2lnbmVyIG9uIHRoZSBhY2NvdW50LgAAAAAAABFTaWduZXJTdGlsbEFjdGl2ZQAAAAAAAA8AAAAiQSBnaWZ0IGFtb3VudCBpcyB6ZXJvIG9yIG5lZ2F0aXZlLgAAAAAADUludmFsaWRBbW91bnQAAAAAAAAQ",
+            "AAAABQAAAIpQdWJsaXNoZWQgd2hlbmV2ZXIgdGhlIGZhY3RvcnkgZGVwbG95cyBhIG5ldyBzbWFydCBhY2NvdW50LCB3aGljaGV2ZXIKZW50cnlwb2ludCBkZXBsb3llZCBpdC4gVG9wLXVwcyBvZiBleGlzdGluZyBhY2NvdW50cyBkbyBub3QgZW1pdCBpdC4AAAAAAAAAAAAOQWNjb3VudENyZWF0ZWQAAAAAAAEAAAAPYWNjb3VudF9jcmVhdGVkAAAAAAUAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAB9Rmlyc3Qgc2lnbmVyIG9mIHRoZSBhY2NvdW50J3MgZGVmYXVsdCBydWxlOiBhIHBhc3NrZXkgb3Igb3RoZXIKYEV4dGVybmFsYCBzaWduZXIgd2l0aCBpdHMgdmVyaWZpZXIsIG9yIGEgYERlbGVnYXRlZGAgYWRkcmVzcy4AAAAAAAAGc2lnbmVyAAAAAAfQAAAABlNpZ25lcgAAAAAAAAAAAD1BbW91bnQgb2YgdGhlIGZhY3RvcnkncyBmdW5kaW5nIHRva2VuIG1vdmVkIGludG8gdGhlIGFjY291bnQuAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAADGFjY291bnRfd2FzbQAAA+4AAAAgAAAAAAAAAAI=",
+            "AAAABQAAACxQdWJsaXNoZWQgd2hlbiBhIHNlbmRlciBvcGVucyBhIGdpZnQgZXNjcm93LgAAAAAAAAANR2lmdERlcG9zaXRlZAAAAAAAAAEAAAAOZ2lmdF9kZXBvc2l0ZWQAAAAAAAUAAAAAAAAABnNlbmRlcgAAAAAAEwAAAAEAAAAAAAAACWNsYWltX2tleQAAAAAAA+4AAAAgAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAANZXhwaXJ5X2xlZGdlcgAAAAAAAAQAAAAAAAAAAg==",
+            "AAAABQAAADhQdWJsaXNoZWQgd2hlbiBhIGdpZnQgaXMgY2xhaW1lZCBpbnRvIGEgcGFzc2tleSBhY2NvdW50LgAAAAAAAAALR2lmdENsYWltZWQAAAAAAQAAAAxnaWZ0X2NsYWltZWQAAAAEAAAAAAAAAAljbGFpbV9rZXkAAAAAAAPuAAAAIAAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+            "AAAABQAAADlQdWJsaXNoZWQgd2hlbiBhbiBleHBpcmVkIGdpZnQgaXMgcmV0dXJuZWQgdG8gaXRzIHNlbmRlci4AAAAAAAAAAAAADEdpZnRSZWZ1bmRlZAAAAAEAAAANZ2lmdF9yZWZ1bmRlZAAAAAAAAAQAAAAAAAAACWNsYWltX2tleQAAAAAAA+4AAAAgAAAAAQAAAAAAAAAGc2VuZGVyAAAAAAATAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
             "AAAABQAAADlFdmVudCBlbWl0dGVkIHdoZW4gYSBzaW1wbGUgdGhyZXNob2xkIHBvbGljeSBpcyBlbmZvcmNlZC4AAAAAAAAAAAAAFFNpbXBsZVBvbGljeUVuZm9yY2VkAAAAAQAAABZzaW1wbGVfcG9saWN5X2VuZm9yY2VkAAAAAAAEAAAAAAAAAA1zbWFydF9hY2NvdW50AAAAAAAAEwAAAAEAAAAAAAAAB2NvbnRleHQAAAAH0AAAAAdDb250ZXh0AAAAAAAAAAAAAAAAD2NvbnRleHRfcnVsZV9pZAAAAAAEAAAAAAAAAAAAAAAVYXV0aGVudGljYXRlZF9zaWduZXJzAAAAAAAD6gAAB9AAAAAGU2lnbmVyAAAAAAAAAAAAAg==",
             "AAAAAQAAADhJbnN0YWxsYXRpb24gcGFyYW1ldGVycyBmb3IgdGhlIHNpbXBsZSB0aHJlc2hvbGQgcG9saWN5LgAAAAAAAAAcU2ltcGxlVGhyZXNob2xkQWNjb3VudFBhcmFtcwAAAAEAAAA5VGhlIG1pbmltdW0gbnVtYmVyIG9mIHNpZ25lcnMgcmVxdWlyZWQgZm9yIGF1dGhvcml6YXRpb24uAAAAAAAACXRocmVzaG9sZAAAAAAAAAQ=",
             "AAAABAAAADNFcnJvciBjb2RlcyBmb3Igc2ltcGxlIHRocmVzaG9sZCBwb2xpY3kgb3BlcmF0aW9ucy4AAAAAAAAAABRTaW1wbGVUaHJlc2hvbGRFcnJvcgAAAAQAAABEVGhlIHNtYXJ0IGFjY291bnQgZG9lcyBub3QgaGF2ZSBhIHNpbXBsZSB0aHJlc2hvbGQgcG9saWN5IGluc3RhbGxlZC4AAAAYU21hcnRBY2NvdW50Tm90SW5zdGFsbGVkAAAMgAAAAD9XaGVuIHRocmVzaG9sZCBpcyAwIG9yIGV4Y2VlZHMgdGhlIG51bWJlciBvZiBhdmFpbGFibGUgc2lnbmVycy4AAAAAEEludmFsaWRUaHJlc2hvbGQAAAyBAAAALlRoZSB0cmFuc2FjdGlvbiBpcyBub3QgYWxsb3dlZCBieSB0aGlzIHBvbGljeS4AAAAAAApOb3RBbGxvd2VkAAAAAAyCAAAAQlRoZSBjb250ZXh0IHJ1bGUgZm9yIHRoZSBzbWFydCBhY2NvdW50IGhhcyBiZWVuIGFscmVhZHkgaW5zdGFsbGVkLgAAAAAAEEFscmVhZHlJbnN0YWxsZWQAAAyD",
@@ -227,6 +357,50 @@ export class Client extends ContractClient {
     }
     fromJSON = {
         create_account: (this.txFromJSON),
+        create_account_with_assets: (this.txFromJSON),
+        create_account_with_config: (this.txFromJSON),
+        create_accounts: (this.txFromJSON),
         get_c_address: (this.txFromJSON),
+        predict_address: (this.txFromJSON),
+        create_account_for_key: (this.txFromJSON),
+        create_account_sponsored: (this.txFromJSON),
+        deposit_gift: (this.txFromJSON),
+        claim_gift: (this.txFromJSON),
+        gift_claim_payload: (this.txFromJSON),
+        refund_gift: (this.txFromJSON),
+        get_gift: (this.txFromJSON),
+        get_account_indices: (this.txFromJSON),
+        lookup_by_key: (this.txFromJSON),
+        lookup_by_credential: (this.txFromJSON),
+        register_signer: (this.txFromJSON),
+        unregister_signer: (this.txFromJSON),
+        set_account_wasm: (this.txFromJSON),
+        set_verifier_wasm: (this.txFromJSON),
+        set_fee: (this.txFromJSON),
+        set_fee_exempt: (this.txFromJSON),
+        set_guardian: (this.txFromJSON),
+        pause: (this.txFromJSON),
+        unpause: (this.txFromJSON),
+        is_paused: (this.txFromJSON),
+        get_guardian: (this.txFromJSON),
+        get_fee: (this.txFromJSON),
+        get_treasury: (this.txFromJSON),
+        is_fee_exempt: (this.txFromJSON),
+        funding_token: (this.txFromJSON),
+        get_admin: (this.txFromJSON),
+        get_account_wasm: (this.txFromJSON),
+        get_account_wasm_version: (this.txFromJSON),
+        get_account_wasm_at: (this.txFromJSON),
+        is_approved_account_wasm: (this.txFromJSON),
+        get_verifier_wasm: (this.txFromJSON),
+        get_verifier_wasm_version: (this.txFromJSON),
+        get_verifier_wasm_at: (this.txFromJSON),
+        register_verifier: (this.txFromJSON),
+        remove_verifier: (this.txFromJSON),
+        get_verifier_kinds: (this.txFromJSON),
+        get_verifier_address: (this.txFromJSON),
+        is_verifier_deployed: (this.txFromJSON),
+        ensure_verifier: (this.txFromJSON),
+        get_verifier_kind_wasm: (this.txFromJSON),
     };
 }
@@ -409,27 +409,738 @@ export interface WebAuthnSigData {
   signature: Buffer;
 }
 
+/**
+ * An additional context rule for a new account. Mirrors the smart account's
+ * `ContextRuleParams` constructor argument.
+ */
+export interface ContextRuleParams {
+  context_type: ContextRuleType;
+  name: string;
+  policies: Map<string, any>;
+  signers: Array<Signer>;
+  valid_until: Option<u32>;
+}
+
+/**
+ * A signer to install on a new account. The factory resolves each spec to a
+ * `Signer`, pointing `External` signers at the verifier it manages for the
+ * spec's kind, so callers cannot bind accounts to arbitrary verifiers.
+ */
+export type SignerSpec =
+  | { tag: "WebAuthn"; values: readonly [Buffer, Option<Buffer>] }
+  | { tag: "Ed25519"; values: readonly [Buffer] }
+  | { tag: "Delegated"; values: readonly [string] }
+  | { tag: "External"; values: readonly [string, Buffer] };
+
+/**
+ * `ContextRuleParams` with signer specs instead of resolved signers.
+ */
+export interface ContextRuleSpec {
+  context_type: ContextRuleType;
+  name: string;
+  policies: Map<string, any>;
+  signers: Array<SignerSpec>;
+  valid_until: Option<u32>;
+}
+
+/**
+ * Full initial configuration of a smart account: the default rule's signers
+ * and policies (policy address to install param), plus any extra rules.
+ */
+export interface AccountConfig {
+  context_rules: Array<ContextRuleSpec>;
+  policies: Map<string, any>;
+  signers: Array<SignerSpec>;
+}
+
+/**
+ * Fee the factory charges funders for each account it deploys, in its
+ * funding token. Topping up an existing account is free.
+ */
+export type FeeConfig =
+  | { tag: "Disabled"; values: void }
+  | { tag: "Flat"; values: readonly [i128] }
+  | { tag: "Bps"; values: readonly [u32] };
+
+/**
+ * Tokens held by the factory until the holder of the claim key creates
+ * their account, or until `expiry_ledger` passes and the sender takes them
+ * back.
+ */
+export interface Gift {
+  amount: i128;
+  /**
+   * Last ledger at which the gift can be claimed.
+   */
+  expiry_ledger: u32;
+  /**
+   * Account fee paid by the sender in the funding token at deposit. It
+   * goes to the treasury if the claim deploys the account and back to the
+   * sender otherwise.
+   */
+  fee: i128;
+  sender: string;
+  token: string;
+}
+
+export type Entry =
+  | { tag: "Key"; values: readonly [Buffer] }
+  | { tag: "Credential"; values: readonly [Buffer] };
+
+export interface Slot {
+  entry: Entry;
+  position: u32;
+}
+
+export interface Member {
+  account: string;
+  entry: Entry;
+}
+
+export const FactoryError = {
+  /**
+   * An account already exists at the requested address, but the supplied
+   * key is not a signer on its default context rule.
+   */
+  1: { message: "SignerMismatch" },
+  /**
+   * An account already exists at the requested address and the entrypoint
+   * cannot top it up.
+   */
+  2: { message: "AccountExists" },
+  /**
+   * An account configuration has no signers on its default rule.
+   */
+  3: { message: "NoSigners" },
+  /**
+   * A gift escrow is already open under this claim key.
+   */
+  4: { message: "GiftExists" },
+  /**
+   * No gift escrow is open under this claim key.
+   */
+  5: { message: "GiftNotFound" },
+  /**
+   * The gift's expiry ledger has passed, or would already have passed
+   * when depositing.
+   */
+  6: { message: "GiftExpired" },
+  /**
+   * The gift can still be claimed, so it cannot be refunded yet.
+   */
+  7: { message: "GiftNotExpired" },
+  /**
+   * A batch has more entries than one call may deploy.
+   */
+  8: { message: "BatchTooLarge" },
+  /**
+   * A flat fee is negative or a basis-point fee exceeds 10 000.
+   */
+  9: { message: "InvalidFee" },
+  /**
+   * The caller is neither the admin nor the guardian.
+   */
+  10: { message: "Unauthorized" },
+  /**
+   * The factory is paused and does not create or fund accounts.
+   */
+  11: { message: "Paused" },
+  /**
+   * A signer spec names a verifier kind the factory has no WASM for.
+   */
+  12: { message: "UnknownVerifier" },
+  /**
+   * The signer hooks were called by an account this factory did not
+   * deploy.
+   */
+  13: { message: "UnknownAccount" },
+  /**
+   * The key data to index is not a signer on the account's default rules.
+   */
+  14: { message: "SignerNotFound" },
+  /**
+   * The key data to drop from the index is still a signer on the account.
+   */
+  15: { message: "SignerStillActive" },
+  /**
+   * A gift amount is zero or negative.
+   */
+  16: { message: "InvalidAmount" },
+};
+
 export interface Client {
   /**
    * Construct and simulate a create_account transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Deploy an account contract and add a passkey to it. Lastly transfer funds to the contract's account.
    *
+   * If the account already exists this only tops it up, provided `key` is a
+   * signer on its default context rule.
+   *
+   * `index` selects which of the funder's accounts to create, so one
+   * G-address can own several C-addresses. `None` is index 0, which keeps
+   * the address the factory has always derived for a funder.
+   *
+   * `credential_id` is the WebAuthn credential ID of the passkey. It is
+   * stored after the public key in the signer's key data so clients can
+   * build `allowCredentials` from on-chain state.
    */
   create_account: (
-    { funder, key, amount }: { funder: string; key: Buffer; amount: i128 },
+    {
+      funder,
+      key,
+      credential_id,
+      amount,
+      index,
+    }: {
+      funder: string;
+      key: Buffer;
+      credential_id: Option<Buffer>;
+      amount: i128;
+      index: Option<u32>;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a create_account_with_assets transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Same as `create_account`, but funds the account with any number of
+   * SEP-41 tokens (e.g. XLM, USDC, EURC SACs) in a single transaction.
+   * Each `(token, amount)` pair is transferred from `funder` in order.
+   */
+  create_account_with_assets: (
+    {
+      funder,
+      key,
+      credential_id,
+      assets,
+      index,
+    }: {
+      funder: string;
+      key: Buffer;
+      credential_id: Option<Buffer>;
+      assets: Array<readonly [string, i128]>;
+      index: Option<u32>;
+    },
     options?: MethodOptions,
   ) => Promise<AssembledTransaction<string>>;
 
+  /**
+   * Construct and simulate a create_account_with_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deploy an account with several initial signers, policies and extra
+   * context rules in one call, then fund it with `amount` of the funding
+   * token. Signers are given as `SignerSpec`s (passkey, Ed25519 key,
+   * delegated address or another registered verifier kind); `External`
+   * specs must name a kind registered with `register_verifier`. Unlike
+   * `create_account`, this fails if the account at `index` already exists,
+   * since an existing configuration cannot be reconciled.
+   */
+  create_account_with_config: (
+    {
+      funder,
+      config,
+      amount,
+      index,
+    }: {
+      funder: string;
+      config: AccountConfig;
+      amount: i128;
+      index: Option<u32>;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a create_accounts transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deploy and fund several of the funder's accounts in one call. Each
+   * entry is `(key, credential_id, amount, index)` and behaves like
+   * `create_account` with those arguments, including topping up accounts
+   * that already exist. At most `MAX_BATCH_SIZE` (3) entries are accepted.
+   */
+  create_accounts: (
+    {
+      funder,
+      entries,
+    }: {
+      funder: string;
+      entries: Array<readonly [Buffer, Option<Buffer>, i128, u32]>;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<string>>>;
+
   /**
    * Construct and simulate a get_c_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    */
   get_c_address: (
+    { funder, index }: { funder: string; index: Option<u32> },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a predict_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Predict the C-address of the account owned by `key`. The address only
+   * depends on the factory, the passkey and its credential ID, so it can
+   * be shared and funded before the user has a G-address or the account
+   * exists.
+   */
+  predict_address: (
+    { key, credential_id }: { key: Buffer; credential_id: Option<Buffer> },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a create_account_for_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deploy the account at `predict_address(key, credential_id)` if it does
+   * not exist yet, then transfer `amount` from `funder` into it. Any funder
+   * can do this, since the address commits to the signer it is created
+   * with.
+   */
+  create_account_for_key: (
+    {
+      key,
+      credential_id,
+      funder,
+      amount,
+    }: {
+      key: Buffer;
+      credential_id: Option<Buffer>;
+      funder: string;
+      amount: i128;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a create_account_sponsored transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sponsored variant of `create_account_for_key`. `relayer` submits the
+   * transaction and pays its fees; `funder` only signs an authorization
+   * bound to `(key, credential_id, amount)`, so the relayer cannot
+   * redirect the funds or change how much is moved.
+   */
+  create_account_sponsored: (
+    {
+      relayer,
+      funder,
+      key,
+      credential_id,
+      amount,
+    }: {
+      relayer: string;
+      funder: string;
+      key: Buffer;
+      credential_id: Option<Buffer>;
+      amount: i128;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a deposit_gift transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hold `amount` of `token` in escrow until it is claimed with a
+   * signature from the Ed25519 `claim_key`. The gift can be claimed up to
+   * and including `expiry_ledger`; after that only `refund_gift` can
+   * release it. The sender also escrows the account fee, in the funding
+   * token, since the claim may deploy an account.
+   */
+  deposit_gift: (
+    {
+      sender,
+      token,
+      amount,
+      claim_key,
+      expiry_ledger,
+    }: {
+      sender: string;
+      token: string;
+      amount: i128;
+      claim_key: Buffer;
+      expiry_ledger: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a claim_gift transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deploy the account at `predict_address(key, credential_id)` if needed
+   * and move the gift held under `claim_key` into it. No auth is
+   * required: `signature` is the claim key's signature over
+   * `gift_claim_payload(claim_key, key, credential_id)`, which binds the
+   * gift to that account (see the `gift` module docs). The escrowed fee
+   * goes to the treasury if this deploys the account, else to the sender.
+   */
+  claim_gift: (
+    {
+      claim_key,
+      key,
+      credential_id,
+      signature,
+    }: {
+      claim_key: Buffer;
+      key: Buffer;
+      credential_id: Option<Buffer>;
+      signature: Buffer;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a gift_claim_payload transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The message `claim_key` must sign for `claim_gift` to send its gift
+   * to the account of `key` and `credential_id`.
+   */
+  gift_claim_payload: (
+    {
+      claim_key,
+      key,
+      credential_id,
+    }: {
+      claim_key: Buffer;
+      key: Buffer;
+      credential_id: Option<Buffer>;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Buffer>>;
+
+  /**
+   * Construct and simulate a refund_gift transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Return an expired, unclaimed gift and its escrowed fee to the sender.
+   * Anyone may call this, since the funds can only go back to the sender.
+   */
+  refund_gift: (
+    { claim_key }: { claim_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a get_gift transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The open gift escrow under `claim_key`, if any.
+   */
+  get_gift: (
+    { claim_key }: { claim_key: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<Gift>>>;
+
+  /**
+   * Construct and simulate a get_account_indices transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Indices the funder has already used with `create_account`.
+   */
+  get_account_indices: (
     { funder }: { funder: string },
     options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<u32>>>;
+
+  /**
+   * Construct and simulate a lookup_by_key transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accounts that were created with, or have registered, this passkey
+   * public key as a signer. Returns up to `limit` (at most 50) accounts
+   * from position `start`; a shorter page means there are no more.
+   */
+  lookup_by_key: (
+    { key, start, limit }: { key: Buffer; start: u32; limit: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<string>>>;
+
+  /**
+   * Construct and simulate a lookup_by_credential transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accounts that were created with, or have registered, a signer with
+   * this WebAuthn credential ID. Paginated like `lookup_by_key`.
+   */
+  lookup_by_credential: (
+    {
+      credential_id,
+      start,
+      limit,
+    }: {
+      credential_id: Buffer;
+      start: u32;
+      limit: u32;
+    },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<string>>>;
+
+  /**
+   * Construct and simulate a register_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hook for an account to index a signer it added. `key_data` is the
+   * `Signer::External` key data: a 65-byte public key optionally followed
+   * by the credential ID. Only accounts this factory deployed may call it,
+   * and `key_data` must be a signer on one of their default context rules.
+   */
+  register_signer: (
+    { account, key_data }: { account: string; key_data: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a unregister_signer transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hook for an account to drop a signer it removed from the index. Fails
+   * while `key_data` is still a signer on a default context rule.
+   */
+  unregister_signer: (
+    { account, key_data }: { account: string; key_data: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_account_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace the smart-account WASM used for new deployments. Accounts
+   * already deployed keep their code. Returns the new version number.
+   */
+  set_account_wasm: (
+    { wasm_hash }: { wasm_hash: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a set_verifier_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace the WebAuthn verifier WASM and deploy the new verifier. It
+   * lives at a new address; accounts deployed earlier keep pointing at
+   * the old one. Returns the new version number.
+   */
+  set_verifier_wasm: (
+    { wasm_hash }: { wasm_hash: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a set_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the fee charged for each account the factory deploys and the
+   * treasury it is paid to. The fee is paid in the funding token by the
+   * funder on top of `amount`; top-ups of existing accounts are free.
+   * `FeeConfig::Disabled` turns it off.
+   */
+  set_fee: (
+    { fee, treasury }: { fee: FeeConfig; treasury: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_fee_exempt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Exempt `funder` from the fee, or make it pay again.
+   */
+  set_fee_exempt: (
+    { funder, exempt }: { funder: string; exempt: boolean },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a set_guardian transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set or clear the guardian, a second address (e.g. an ops key or
+   * monitoring service) that can pause the factory without the admin.
+   */
+  set_guardian: (
+    { guardian }: { guardian: Option<string> },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stop all account creation, e.g. when the account WASM has a known
+   * vulnerability. Every `create_account*` entrypoint (top-ups of existing
+   * accounts included), `claim_gift` and `ensure_verifier` fail with
+   * `Paused`; gift deposits and refunds and address lookups keep working.
+   * `caller` must be the admin or the guardian.
+   */
+  pause: (
+    { caller }: { caller: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resume account creation. Only the admin can unpause, so a guardian key can
+   * stop the factory quickly but not lift a pause on its own.
+   */
+  unpause: (options?: MethodOptions) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_paused: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a get_guardian transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_guardian: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<string>>>;
+
+  /**
+   * Construct and simulate a get_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_fee: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<FeeConfig>>;
+
+  /**
+   * Construct and simulate a get_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_treasury: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<string>>>;
+
+  /**
+   * Construct and simulate a is_fee_exempt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  is_fee_exempt: (
+    { funder }: { funder: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a funding_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The token accounts are funded with.
+   */
+  funding_token: (
+    options?: MethodOptions,
   ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a get_account_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_account_wasm: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Buffer>>;
+
+  /**
+   * Construct and simulate a get_account_wasm_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_account_wasm_version: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_account_wasm_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Look up the account WASM hash recorded for a past or current version.
+   */
+  get_account_wasm_at: (
+    { version }: { version: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<Buffer>>>;
+
+  /**
+   * Construct and simulate a is_approved_account_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether accounts that name the factory as their upgrade authority may
+   * install `wasm_hash`. Only the current account WASM is approved, so
+   * accounts cannot move back to a version the admin has replaced.
+   */
+  is_approved_account_wasm: (
+    { wasm_hash }: { wasm_hash: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a get_verifier_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_verifier_wasm: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Buffer>>;
+
+  /**
+   * Construct and simulate a get_verifier_wasm_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   */
+  get_verifier_wasm_version: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<u32>>;
+
+  /**
+   * Construct and simulate a get_verifier_wasm_at transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Look up the verifier WASM hash recorded for a past or current version.
+   */
+  get_verifier_wasm_at: (
+    { version }: { version: u32 },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<Buffer>>>;
+
+  /**
+   * Construct and simulate a register_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allow `SignerSpec`s of `kind` and verify them with a shared instance
+   * of `wasm_hash`, which is deployed right away. Registering `webauthn`
+   * is the same as `set_verifier_wasm`.
+   */
+  register_verifier: (
+    { kind, wasm_hash }: { kind: string; wasm_hash: Buffer },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a remove_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stop accepting signers of `kind` for new accounts. Accounts already
+   * using the verifier are unaffected. The built-in `webauthn` kind cannot
+   * be removed.
+   */
+  remove_verifier: (
+    { kind }: { kind: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<null>>;
+
+  /**
+   * Construct and simulate a get_verifier_kinds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Every signer kind new accounts can use, starting with `webauthn`.
+   */
+  get_verifier_kinds: (
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Array<string>>>;
+
+  /**
+   * Construct and simulate a get_verifier_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Address of the shared verifier for `kind`, whether or not it has been
+   * deployed yet. Clients can use it to build `Signer::External` values
+   * without a transaction.
+   */
+  get_verifier_address: (
+    { kind }: { kind: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a is_verifier_deployed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Whether the shared verifier for `kind` has been deployed.
+   */
+  is_verifier_deployed: (
+    { kind }: { kind: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<boolean>>;
+
+  /**
+   * Construct and simulate a ensure_verifier transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Deploy the shared verifier for `kind` if needed and return its address.
+   * Verifiers are deployed when registered, except the WebAuthn verifier
+   * given to the constructor, which deploys with the first account.
+   */
+  ensure_verifier: (
+    { kind }: { kind: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<string>>;
+
+  /**
+   * Construct and simulate a get_verifier_kind_wasm transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * The verifier WASM hash registered for `kind`, if any.
+   */
+  get_verifier_kind_wasm: (
+    { kind }: { kind: string },
+    options?: MethodOptions,
+  ) => Promise<AssembledTransaction<Option<Buffer>>>;
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
+    /** Constructor/Initialization Args for the contract's `__constructor` method */
+    {
+      admin,
+      account_wasm,
+      verifier_wasm,
+      funding_token,
+    }: {
+      admin: string;
+      account_wasm: Buffer;
+      verifier_wasm: Buffer;
+      funding_token: Option<string>;
+    },
     /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
     options: MethodOptions &
       Omit<ContractClientOptions, "contractId"> & {
@@ -441,14 +1152,75 @@ export class Client extends ContractClient {
         format?: "hex" | "base64";
       },
   ): Promise<AssembledTransaction<T>> {
-    return ContractClient.deploy(null, options);
+    return ContractClient.deploy(
+      { admin, account_wasm, verifier_wasm, funding_token },
+      options,
+    );
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
       new ContractSpec([
-        "AAAAAAAAAAAAAAANX19jb25zdHJ1Y3RvcgAAAAAAAAAAAAAA",
-        "AAAAAAAAAGVEZXBsb3kgYW4gYWNjb3VudCBjb250cmFjdCBhbmQgYWRkIGEgcGFzc2tleSB0byBpdC4gTGFzdGx5IHRyYW5zZmVyIGZ1bmRzIHRvIHRoZSBjb250cmFjdCdzIGFjY291bnQuCgAAAAAAAA5jcmVhdGVfYWNjb3VudAAAAAAAAwAAAAAAAAAGZnVuZGVyAAAAAAATAAAAAAAAAANrZXkAAAAD7gAAAEEAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAAT",
-        "AAAAAAAAAAAAAAANZ2V0X2NfYWRkcmVzcwAAAAAAAAEAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAEAAAAT",
+        "AAAAAAAAAPJJbml0aWFsaXplIHRoZSBmYWN0b3J5IHdpdGggYW4gYWRtaW4gYW5kIHRoZSBpbml0aWFsIGFjY291bnQgYW5kCnZlcmlmaWVyIFdBU00gaGFzaGVzIChib3RoIHJlY29yZGVkIGFzIHZlcnNpb24gMSkuCgpgZnVuZGluZ190b2tlbmAgaXMgdGhlIFNFUC00MSB0b2tlbiB0aGF0IGBhbW91bnRgcyBhbmQgZmVlcyBhcmUgcGFpZAppbi4gYE5vbmVgIHVzZXMgdGhlIG5ldHdvcmsncyBuYXRpdmUgWExNIGFzc2V0IGNvbnRyYWN0LgAAAAAADV9fY29uc3RydWN0b3IAAAAAAAAEAAAAAAAAAAVhZG1pbgAAAAAAABMAAAAAAAAADGFjY291bnRfd2FzbQAAA+4AAAAgAAAAAAAAAA12ZXJpZmllcl93YXNtAAAAAAAD7gAAACAAAAAAAAAADWZ1bmRpbmdfdG9rZW4AAAAAAAPoAAAAEwAAAAA=",
+        "AAAAAAAAAklEZXBsb3kgYW4gYWNjb3VudCBjb250cmFjdCBhbmQgYWRkIGEgcGFzc2tleSB0byBpdC4gTGFzdGx5IHRyYW5zZmVyIGZ1bmRzIHRvIHRoZSBjb250cmFjdCdzIGFjY291bnQuCgpJZiB0aGUgYWNjb3VudCBhbHJlYWR5IGV4aXN0cyB0aGlzIG9ubHkgdG9wcyBpdCB1cCwgcHJvdmlkZWQgYGtleWAgaXMgYQpzaWduZXIgb24gaXRzIGRlZmF1bHQgY29udGV4dCBydWxlLgoKYGluZGV4YCBzZWxlY3RzIHdoaWNoIG9mIHRoZSBmdW5kZXIncyBhY2NvdW50cyB0byBjcmVhdGUsIHNvIG9uZQpHLWFkZHJlc3MgY2FuIG93biBzZXZlcmFsIEMtYWRkcmVzc2VzLiBgTm9uZWAgaXMgaW5kZXggMCwgd2hpY2gga2VlcHMKdGhlIGFkZHJlc3MgdGhlIGZhY3RvcnkgaGFzIGFsd2F5cyBkZXJpdmVkIGZvciBhIGZ1bmRlci4KCmBjcmVkZW50aWFsX2lkYCBpcyB0aGUgV2ViQXV0aG4gY3JlZGVudGlhbCBJRCBvZiB0aGUgcGFzc2tleS4gSXQgaXMKc3RvcmVkIGFmdGVyIHRoZSBwdWJsaWMga2V5IGluIHRoZSBzaWduZXIncyBrZXkgZGF0YSBzbyBjbGllbnRzIGNhbgpidWlsZCBgYWxsb3dDcmVkZW50aWFsc2AgZnJvbSBvbi1jaGFpbiBzdGF0ZS4AAAAAAAAOY3JlYXRlX2FjY291bnQAAAAAAAUAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAAAAAADa2V5AAAAA+4AAABBAAAAAAAAAA1jcmVkZW50aWFsX2lkAAAAAAAD6AAAAA4AAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAFaW5kZXgAAAAAAAPoAAAABAAAAAEAAAAT",
+        "AAAAAAAAAMhTYW1lIGFzIGBjcmVhdGVfYWNjb3VudGAsIGJ1dCBmdW5kcyB0aGUgYWNjb3VudCB3aXRoIGFueSBudW1iZXIgb2YKU0VQLTQxIHRva2VucyAoZS5nLiBYTE0sIFVTREMsIEVVUkMgU0FDcykgaW4gYSBzaW5nbGUgdHJhbnNhY3Rpb24uCkVhY2ggYCh0b2tlbiwgYW1vdW50KWAgcGFpciBpcyB0cmFuc2ZlcnJlZCBmcm9tIGBmdW5kZXJgIGluIG9yZGVyLgAAABpjcmVhdGVfYWNjb3VudF93aXRoX2Fzc2V0cwAAAAAABQAAAAAAAAAGZnVuZGVyAAAAAAATAAAAAAAAAANrZXkAAAAD7gAAAEEAAAAAAAAADWNyZWRlbnRpYWxfaWQAAAAAAAPoAAAADgAAAAAAAAAGYXNzZXRzAAAAAAPqAAAD7QAAAAIAAAATAAAACwAAAAAAAAAFaW5kZXgAAAAAAAPoAAAABAAAAAEAAAAT",
+        "AAAAAAAAActEZXBsb3kgYW4gYWNjb3VudCB3aXRoIHNldmVyYWwgaW5pdGlhbCBzaWduZXJzLCBwb2xpY2llcyBhbmQgZXh0cmEKY29udGV4dCBydWxlcyBpbiBvbmUgY2FsbCwgdGhlbiBmdW5kIGl0IHdpdGggYGFtb3VudGAgb2YgdGhlIGZ1bmRpbmcKdG9rZW4uIFNpZ25lcnMgYXJlIGdpdmVuIGFzIGBTaWduZXJTcGVjYHMgKHBhc3NrZXksIEVkMjU1MTkga2V5LApkZWxlZ2F0ZWQgYWRkcmVzcyBvciBhbm90aGVyIHJlZ2lzdGVyZWQgdmVyaWZpZXIga2luZCk7IGBFeHRlcm5hbGAKc3BlY3MgbXVzdCBuYW1lIGEga2luZCByZWdpc3RlcmVkIHdpdGggYHJlZ2lzdGVyX3ZlcmlmaWVyYC4gVW5saWtlCmBjcmVhdGVfYWNjb3VudGAsIHRoaXMgZmFpbHMgaWYgdGhlIGFjY291bnQgYXQgYGluZGV4YCBhbHJlYWR5IGV4aXN0cywKc2luY2UgYW4gZXhpc3RpbmcgY29uZmlndXJhdGlvbiBjYW5ub3QgYmUgcmVjb25jaWxlZC4AAAAAGmNyZWF0ZV9hY2NvdW50X3dpdGhfY29uZmlnAAAAAAAEAAAAAAAAAAZmdW5kZXIAAAAAABMAAAAAAAAABmNvbmZpZwAAAAAH0AAAAA1BY2NvdW50Q29uZmlnAAAAAAAAAAAAAAZhbW91bnQAAAAAAAsAAAAAAAAABWluZGV4AAAAAAAD6AAAAAQAAAABAAAAEw==",
+        "AAAAAAAAAQ5EZXBsb3kgYW5kIGZ1bmQgc2V2ZXJhbCBvZiB0aGUgZnVuZGVyJ3MgYWNjb3VudHMgaW4gb25lIGNhbGwuIEVhY2gKZW50cnkgaXMgYChrZXksIGNyZWRlbnRpYWxfaWQsIGFtb3VudCwgaW5kZXgpYCBhbmQgYmVoYXZlcyBsaWtlCmBjcmVhdGVfYWNjb3VudGAgd2l0aCB0aG9zZSBhcmd1bWVudHMsIGluY2x1ZGluZyB0b3BwaW5nIHVwIGFjY291bnRzCnRoYXQgYWxyZWFkeSBleGlzdC4gQXQgbW9zdCBgTUFYX0JBVENIX1NJWkVgICgzKSBlbnRyaWVzIGFyZSBhY2NlcHRlZC4AAAAAAA9jcmVhdGVfYWNjb3VudHMAAAAAAgAAAAAAAAAGZnVuZGVyAAAAAAATAAAAAAAAAAdlbnRyaWVzAAAAA+oAAAPtAAAABAAAA+4AAABBAAAD6AAAAA4AAAALAAAABAAAAAEAAAPqAAAAEw==",
+        "AAAAAAAAAAAAAAANZ2V0X2NfYWRkcmVzcwAAAAAAAAIAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAAAAAAFaW5kZXgAAAAAAAPoAAAABAAAAAEAAAAT",
+        "AAAAAAAAANZQcmVkaWN0IHRoZSBDLWFkZHJlc3Mgb2YgdGhlIGFjY291bnQgb3duZWQgYnkgYGtleWAuIFRoZSBhZGRyZXNzIG9ubHkKZGVwZW5kcyBvbiB0aGUgZmFjdG9yeSwgdGhlIHBhc3NrZXkgYW5kIGl0cyBjcmVkZW50aWFsIElELCBzbyBpdCBjYW4KYmUgc2hhcmVkIGFuZCBmdW5kZWQgYmVmb3JlIHRoZSB1c2VyIGhhcyBhIEctYWRkcmVzcyBvciB0aGUgYWNjb3VudApleGlzdHMuAAAAAAAPcHJlZGljdF9hZGRyZXNzAAAAAAIAAAAAAAAAA2tleQAAAAPuAAAAQQAAAAAAAAANY3JlZGVudGlhbF9pZAAAAAAAA+gAAAAOAAAAAQAAABM=",
+        "AAAAAAAAANdEZXBsb3kgdGhlIGFjY291bnQgYXQgYHByZWRpY3RfYWRkcmVzcyhrZXksIGNyZWRlbnRpYWxfaWQpYCBpZiBpdCBkb2VzCm5vdCBleGlzdCB5ZXQsIHRoZW4gdHJhbnNmZXIgYGFtb3VudGAgZnJvbSBgZnVuZGVyYCBpbnRvIGl0LiBBbnkgZnVuZGVyCmNhbiBkbyB0aGlzLCBzaW5jZSB0aGUgYWRkcmVzcyBjb21taXRzIHRvIHRoZSBzaWduZXIgaXQgaXMgY3JlYXRlZAp3aXRoLgAAAAAWY3JlYXRlX2FjY291bnRfZm9yX2tleQAAAAAABAAAAAAAAAADa2V5AAAAA+4AAABBAAAAAAAAAA1jcmVkZW50aWFsX2lkAAAAAAAD6AAAAA4AAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAABM=",
+        "AAAAAAAAAPdTcG9uc29yZWQgdmFyaWFudCBvZiBgY3JlYXRlX2FjY291bnRfZm9yX2tleWAuIGByZWxheWVyYCBzdWJtaXRzIHRoZQp0cmFuc2FjdGlvbiBhbmQgcGF5cyBpdHMgZmVlczsgYGZ1bmRlcmAgb25seSBzaWducyBhbiBhdXRob3JpemF0aW9uCmJvdW5kIHRvIGAoa2V5LCBjcmVkZW50aWFsX2lkLCBhbW91bnQpYCwgc28gdGhlIHJlbGF5ZXIgY2Fubm90CnJlZGlyZWN0IHRoZSBmdW5kcyBvciBjaGFuZ2UgaG93IG11Y2ggaXMgbW92ZWQuAAAAABhjcmVhdGVfYWNjb3VudF9zcG9uc29yZWQAAAAFAAAAAAAAAAdyZWxheWVyAAAAABMAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAAAAAADa2V5AAAAA+4AAABBAAAAAAAAAA1jcmVkZW50aWFsX2lkAAAAAAAD6AAAAA4AAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAAT",
+        "AAAAAAAAATZIb2xkIGBhbW91bnRgIG9mIGB0b2tlbmAgaW4gZXNjcm93IHVudGlsIGl0IGlzIGNsYWltZWQgd2l0aCBhCnNpZ25hdHVyZSBmcm9tIHRoZSBFZDI1NTE5IGBjbGFpbV9rZXlgLiBUaGUgZ2lmdCBjYW4gYmUgY2xhaW1lZCB1cCB0bwphbmQgaW5jbHVkaW5nIGBleHBpcnlfbGVkZ2VyYDsgYWZ0ZXIgdGhhdCBvbmx5IGByZWZ1bmRfZ2lmdGAgY2FuCnJlbGVhc2UgaXQuIFRoZSBzZW5kZXIgYWxzbyBlc2Nyb3dzIHRoZSBhY2NvdW50IGZlZSwgaW4gdGhlIGZ1bmRpbmcKdG9rZW4sIHNpbmNlIHRoZSBjbGFpbSBtYXkgZGVwbG95IGFuIGFjY291bnQuAAAAAAAMZGVwb3NpdF9naWZ0AAAABQAAAAAAAAAGc2VuZGVyAAAAAAATAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAJY2xhaW1fa2V5AAAAAAAD7gAAACAAAAAAAAAADWV4cGlyeV9sZWRnZXIAAAAAAAAEAAAAAA==",
+        "AAAAAAAAAYlEZXBsb3kgdGhlIGFjY291bnQgYXQgYHByZWRpY3RfYWRkcmVzcyhrZXksIGNyZWRlbnRpYWxfaWQpYCBpZiBuZWVkZWQKYW5kIG1vdmUgdGhlIGdpZnQgaGVsZCB1bmRlciBgY2xhaW1fa2V5YCBpbnRvIGl0LiBObyBhdXRoIGlzCnJlcXVpcmVkOiBgc2lnbmF0dXJlYCBpcyB0aGUgY2xhaW0ga2V5J3Mgc2lnbmF0dXJlIG92ZXIKYGdpZnRfY2xhaW1fcGF5bG9hZChjbGFpbV9rZXksIGtleSwgY3JlZGVudGlhbF9pZClgLCB3aGljaCBiaW5kcyB0aGUKZ2lmdCB0byB0aGF0IGFjY291bnQgKHNlZSB0aGUgYGdpZnRgIG1vZHVsZSBkb2NzKS4gVGhlIGVzY3Jvd2VkIGZlZQpnb2VzIHRvIHRoZSB0cmVhc3VyeSBpZiB0aGlzIGRlcGxveXMgdGhlIGFjY291bnQsIGVsc2UgdG8gdGhlIHNlbmRlci4AAAAAAAAKY2xhaW1fZ2lmdAAAAAAABAAAAAAAAAAJY2xhaW1fa2V5AAAAAAAD7gAAACAAAAAAAAAAA2tleQAAAAPuAAAAQQAAAAAAAAANY3JlZGVudGlhbF9pZAAAAAAAA+gAAAAOAAAAAAAAAAlzaWduYXR1cmUAAAAAAAPuAAAAQAAAAAEAAAAT",
+        "AAAAAAAAAHBUaGUgbWVzc2FnZSBgY2xhaW1fa2V5YCBtdXN0IHNpZ24gZm9yIGBjbGFpbV9naWZ0YCB0byBzZW5kIGl0cyBnaWZ0CnRvIHRoZSBhY2NvdW50IG9mIGBrZXlgIGFuZCBgY3JlZGVudGlhbF9pZGAuAAAAEmdpZnRfY2xhaW1fcGF5bG9hZAAAAAAAAwAAAAAAAAAJY2xhaW1fa2V5AAAAAAAD7gAAACAAAAAAAAAAA2tleQAAAAPuAAAAQQAAAAAAAAANY3JlZGVudGlhbF9pZAAAAAAAA+gAAAAOAAAAAQAAAA4=",
+        "AAAAAAAAAItSZXR1cm4gYW4gZXhwaXJlZCwgdW5jbGFpbWVkIGdpZnQgYW5kIGl0cyBlc2Nyb3dlZCBmZWUgdG8gdGhlIHNlbmRlci4KQW55b25lIG1heSBjYWxsIHRoaXMsIHNpbmNlIHRoZSBmdW5kcyBjYW4gb25seSBnbyBiYWNrIHRvIHRoZSBzZW5kZXIuAAAAAAtyZWZ1bmRfZ2lmdAAAAAABAAAAAAAAAAljbGFpbV9rZXkAAAAAAAPuAAAAIAAAAAA=",
+        "AAAAAAAAAC9UaGUgb3BlbiBnaWZ0IGVzY3JvdyB1bmRlciBgY2xhaW1fa2V5YCwgaWYgYW55LgAAAAAIZ2V0X2dpZnQAAAABAAAAAAAAAAljbGFpbV9rZXkAAAAAAAPuAAAAIAAAAAEAAAPoAAAH0AAAAARHaWZ0",
+        "AAAAAAAAADpJbmRpY2VzIHRoZSBmdW5kZXIgaGFzIGFscmVhZHkgdXNlZCB3aXRoIGBjcmVhdGVfYWNjb3VudGAuAAAAAAATZ2V0X2FjY291bnRfaW5kaWNlcwAAAAABAAAAAAAAAAZmdW5kZXIAAAAAABMAAAABAAAD6gAAAAQ=",
+        "AAAAAAAAAMRBY2NvdW50cyB0aGF0IHdlcmUgY3JlYXRlZCB3aXRoLCBvciBoYXZlIHJlZ2lzdGVyZWQsIHRoaXMgcGFzc2tleQpwdWJsaWMga2V5IGFzIGEgc2lnbmVyLiBSZXR1cm5zIHVwIHRvIGBsaW1pdGAgKGF0IG1vc3QgNTApIGFjY291bnRzCmZyb20gcG9zaXRpb24gYHN0YXJ0YDsgYSBzaG9ydGVyIHBhZ2UgbWVhbnMgdGhlcmUgYXJlIG5vIG1vcmUuAAAADWxvb2t1cF9ieV9rZXkAAAAAAAADAAAAAAAAAANrZXkAAAAD7gAAAEEAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAAT",
+        "AAAAAAAAAH9BY2NvdW50cyB0aGF0IHdlcmUgY3JlYXRlZCB3aXRoLCBvciBoYXZlIHJlZ2lzdGVyZWQsIGEgc2lnbmVyIHdpdGgKdGhpcyBXZWJBdXRobiBjcmVkZW50aWFsIElELiBQYWdpbmF0ZWQgbGlrZSBgbG9va3VwX2J5X2tleWAuAAAAABRsb29rdXBfYnlfY3JlZGVudGlhbAAAAAMAAAAAAAAADWNyZWRlbnRpYWxfaWQAAAAAAAAOAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAAEw==",
+        "AAAAAAAAARVIb29rIGZvciBhbiBhY2NvdW50IHRvIGluZGV4IGEgc2lnbmVyIGl0IGFkZGVkLiBga2V5X2RhdGFgIGlzIHRoZQpgU2lnbmVyOjpFeHRlcm5hbGAga2V5IGRhdGE6IGEgNjUtYnl0ZSBwdWJsaWMga2V5IG9wdGlvbmFsbHkgZm9sbG93ZWQKYnkgdGhlIGNyZWRlbnRpYWwgSUQuIE9ubHkgYWNjb3VudHMgdGhpcyBmYWN0b3J5IGRlcGxveWVkIG1heSBjYWxsIGl0LAphbmQgYGtleV9kYXRhYCBtdXN0IGJlIGEgc2lnbmVyIG9uIG9uZSBvZiB0aGVpciBkZWZhdWx0IGNvbnRleHQgcnVsZXMuAAAAAAAAD3JlZ2lzdGVyX3NpZ25lcgAAAAACAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAACGtleV9kYXRhAAAADgAAAAA=",
+        "AAAAAAAAAINIb29rIGZvciBhbiBhY2NvdW50IHRvIGRyb3AgYSBzaWduZXIgaXQgcmVtb3ZlZCBmcm9tIHRoZSBpbmRleC4gRmFpbHMKd2hpbGUgYGtleV9kYXRhYCBpcyBzdGlsbCBhIHNpZ25lciBvbiBhIGRlZmF1bHQgY29udGV4dCBydWxlLgAAAAARdW5yZWdpc3Rlcl9zaWduZXIAAAAAAAACAAAAAAAAAAdhY2NvdW50AAAAABMAAAAAAAAACGtleV9kYXRhAAAADgAAAAA=",
+        "AAAAAAAAAINSZXBsYWNlIHRoZSBzbWFydC1hY2NvdW50IFdBU00gdXNlZCBmb3IgbmV3IGRlcGxveW1lbnRzLiBBY2NvdW50cwphbHJlYWR5IGRlcGxveWVkIGtlZXAgdGhlaXIgY29kZS4gUmV0dXJucyB0aGUgbmV3IHZlcnNpb24gbnVtYmVyLgAAAAAQc2V0X2FjY291bnRfd2FzbQAAAAEAAAAAAAAACXdhc21faGFzaAAAAAAAA+4AAAAgAAAAAQAAAAQ=",
+        "AAAAAAAAALJSZXBsYWNlIHRoZSBXZWJBdXRobiB2ZXJpZmllciBXQVNNIGFuZCBkZXBsb3kgdGhlIG5ldyB2ZXJpZmllci4gSXQKbGl2ZXMgYXQgYSBuZXcgYWRkcmVzczsgYWNjb3VudHMgZGVwbG95ZWQgZWFybGllciBrZWVwIHBvaW50aW5nIGF0CnRoZSBvbGQgb25lLiBSZXR1cm5zIHRoZSBuZXcgdmVyc2lvbiBudW1iZXIuAAAAAAARc2V0X3ZlcmlmaWVyX3dhc20AAAAAAAABAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAAE",
+        "AAAAAAAAAOpTZXQgdGhlIGZlZSBjaGFyZ2VkIGZvciBlYWNoIGFjY291bnQgdGhlIGZhY3RvcnkgZGVwbG95cyBhbmQgdGhlCnRyZWFzdXJ5IGl0IGlzIHBhaWQgdG8uIFRoZSBmZWUgaXMgcGFpZCBpbiB0aGUgZnVuZGluZyB0b2tlbiBieSB0aGUKZnVuZGVyIG9uIHRvcCBvZiBgYW1vdW50YDsgdG9wLXVwcyBvZiBleGlzdGluZyBhY2NvdW50cyBhcmUgZnJlZS4KYEZlZUNvbmZpZzo6RGlzYWJsZWRgIHR1cm5zIGl0IG9mZi4AAAAAAAdzZXRfZmVlAAAAAAIAAAAAAAAAA2ZlZQAAAAfQAAAACUZlZUNvbmZpZwAAAAAAAAAAAAAIdHJlYXN1cnkAAAATAAAAAA==",
+        "AAAAAAAAADNFeGVtcHQgYGZ1bmRlcmAgZnJvbSB0aGUgZmVlLCBvciBtYWtlIGl0IHBheSBhZ2Fpbi4AAAAADnNldF9mZWVfZXhlbXB0AAAAAAACAAAAAAAAAAZmdW5kZXIAAAAAABMAAAAAAAAABmV4ZW1wdAAAAAAAAQAAAAA=",
+        "AAAAAAAAAIFTZXQgb3IgY2xlYXIgdGhlIGd1YXJkaWFuLCBhIHNlY29uZCBhZGRyZXNzIChlLmcuIGFuIG9wcyBrZXkgb3IKbW9uaXRvcmluZyBzZXJ2aWNlKSB0aGF0IGNhbiBwYXVzZSB0aGUgZmFjdG9yeSB3aXRob3V0IHRoZSBhZG1pbi4AAAAAAAAMc2V0X2d1YXJkaWFuAAAAAQAAAAAAAAAIZ3VhcmRpYW4AAAPoAAAAEwAAAAA=",
+        "AAAAAAAAATtTdG9wIGFsbCBhY2NvdW50IGNyZWF0aW9uLCBlLmcuIHdoZW4gdGhlIGFjY291bnQgV0FTTSBoYXMgYSBrbm93bgp2dWxuZXJhYmlsaXR5LiBFdmVyeSBgY3JlYXRlX2FjY291bnQqYCBlbnRyeXBvaW50ICh0b3AtdXBzIG9mIGV4aXN0aW5nCmFjY291bnRzIGluY2x1ZGVkKSwgYGNsYWltX2dpZnRgIGFuZCBgZW5zdXJlX3ZlcmlmaWVyYCBmYWlsIHdpdGgKYFBhdXNlZGA7IGdpZnQgZGVwb3NpdHMgYW5kIHJlZnVuZHMgYW5kIGFkZHJlc3MgbG9va3VwcyBrZWVwIHdvcmtpbmcuCmBjYWxsZXJgIG11c3QgYmUgdGhlIGFkbWluIG9yIHRoZSBndWFyZGlhbi4AAAAABXBhdXNlAAAAAAAAAQAAAAAAAAAGY2FsbGVyAAAAAAATAAAAAA==",
+        "AAAAAAAAAIRSZXN1bWUgYWNjb3VudCBjcmVhdGlvbi4gT25seSB0aGUgYWRtaW4gY2FuIHVucGF1c2UsIHNvIGEgZ3VhcmRpYW4ga2V5IGNhbgpzdG9wIHRoZSBmYWN0b3J5IHF1aWNrbHkgYnV0IG5vdCBsaWZ0IGEgcGF1c2Ugb24gaXRzIG93bi4AAAAHdW5wYXVzZQAAAAAAAAAAAA==",
+        "AAAAAAAAAAAAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAAAAAAAAMZ2V0X2d1YXJkaWFuAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAAAAAAAHZ2V0X2ZlZQAAAAAAAAAAAQAAB9AAAAAJRmVlQ29uZmlnAAAA",
+        "AAAAAAAAAAAAAAAMZ2V0X3RyZWFzdXJ5AAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAAAAAAANaXNfZmVlX2V4ZW1wdAAAAAAAAAEAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAEAAAAB",
+        "AAAAAAAAACNUaGUgdG9rZW4gYWNjb3VudHMgYXJlIGZ1bmRlZCB3aXRoLgAAAAANZnVuZGluZ190b2tlbgAAAAAAAAAAAAABAAAAEw==",
+        "AAAAAAAAAAAAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAAAAAAAAQZ2V0X2FjY291bnRfd2FzbQAAAAAAAAABAAAD7gAAACA=",
+        "AAAAAAAAAAAAAAAYZ2V0X2FjY291bnRfd2FzbV92ZXJzaW9uAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAEVMb29rIHVwIHRoZSBhY2NvdW50IFdBU00gaGFzaCByZWNvcmRlZCBmb3IgYSBwYXN0IG9yIGN1cnJlbnQgdmVyc2lvbi4AAAAAAAATZ2V0X2FjY291bnRfd2FzbV9hdAAAAAABAAAAAAAAAAd2ZXJzaW9uAAAAAAQAAAABAAAD6AAAA+4AAAAg",
+        "AAAAAAAAAMdXaGV0aGVyIGFjY291bnRzIHRoYXQgbmFtZSB0aGUgZmFjdG9yeSBhcyB0aGVpciB1cGdyYWRlIGF1dGhvcml0eSBtYXkKaW5zdGFsbCBgd2FzbV9oYXNoYC4gT25seSB0aGUgY3VycmVudCBhY2NvdW50IFdBU00gaXMgYXBwcm92ZWQsIHNvCmFjY291bnRzIGNhbm5vdCBtb3ZlIGJhY2sgdG8gYSB2ZXJzaW9uIHRoZSBhZG1pbiBoYXMgcmVwbGFjZWQuAAAAABhpc19hcHByb3ZlZF9hY2NvdW50X3dhc20AAAABAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAEAAAAB",
+        "AAAAAAAAAAAAAAARZ2V0X3ZlcmlmaWVyX3dhc20AAAAAAAAAAAAAAQAAA+4AAAAg",
+        "AAAAAAAAAAAAAAAZZ2V0X3ZlcmlmaWVyX3dhc21fdmVyc2lvbgAAAAAAAAAAAAABAAAABA==",
+        "AAAAAAAAAEZMb29rIHVwIHRoZSB2ZXJpZmllciBXQVNNIGhhc2ggcmVjb3JkZWQgZm9yIGEgcGFzdCBvciBjdXJyZW50IHZlcnNpb24uAAAAAAAUZ2V0X3ZlcmlmaWVyX3dhc21fYXQAAAABAAAAAAAAAAd2ZXJzaW9uAAAAAAQAAAABAAAD6AAAA+4AAAAg",
+        "AAAAAAAAAK1BbGxvdyBgU2lnbmVyU3BlY2BzIG9mIGBraW5kYCBhbmQgdmVyaWZ5IHRoZW0gd2l0aCBhIHNoYXJlZCBpbnN0YW5jZQpvZiBgd2FzbV9oYXNoYCwgd2hpY2ggaXMgZGVwbG95ZWQgcmlnaHQgYXdheS4gUmVnaXN0ZXJpbmcgYHdlYmF1dGhuYAppcyB0aGUgc2FtZSBhcyBgc2V0X3ZlcmlmaWVyX3dhc21gLgAAAAAAABFyZWdpc3Rlcl92ZXJpZmllcgAAAAAAAAIAAAAAAAAABGtpbmQAAAARAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
+        "AAAAAAAAAJZTdG9wIGFjY2VwdGluZyBzaWduZXJzIG9mIGBraW5kYCBmb3IgbmV3IGFjY291bnRzLiBBY2NvdW50cyBhbHJlYWR5CnVzaW5nIHRoZSB2ZXJpZmllciBhcmUgdW5hZmZlY3RlZC4gVGhlIGJ1aWx0LWluIGB3ZWJhdXRobmAga2luZCBjYW5ub3QKYmUgcmVtb3ZlZC4AAAAAAA9yZW1vdmVfdmVyaWZpZXIAAAAAAQAAAAAAAAAEa2luZAAAABEAAAAA",
+        "AAAAAAAAAEFFdmVyeSBzaWduZXIga2luZCBuZXcgYWNjb3VudHMgY2FuIHVzZSwgc3RhcnRpbmcgd2l0aCBgd2ViYXV0aG5gLgAAAAAAABJnZXRfdmVyaWZpZXJfa2luZHMAAAAAAAAAAAABAAAD6gAAABE=",
+        "AAAAAAAAAKBBZGRyZXNzIG9mIHRoZSBzaGFyZWQgdmVyaWZpZXIgZm9yIGBraW5kYCwgd2hldGhlciBvciBub3QgaXQgaGFzIGJlZW4KZGVwbG95ZWQgeWV0LiBDbGllbnRzIGNhbiB1c2UgaXQgdG8gYnVpbGQgYFNpZ25lcjo6RXh0ZXJuYWxgIHZhbHVlcwp3aXRob3V0IGEgdHJhbnNhY3Rpb24uAAAAFGdldF92ZXJpZmllcl9hZGRyZXNzAAAAAQAAAAAAAAAEa2luZAAAABEAAAABAAAAEw==",
+        "AAAAAAAAADlXaGV0aGVyIHRoZSBzaGFyZWQgdmVyaWZpZXIgZm9yIGBraW5kYCBoYXMgYmVlbiBkZXBsb3llZC4AAAAAAAAUaXNfdmVyaWZpZXJfZGVwbG95ZWQAAAABAAAAAAAAAARraW5kAAAAEQAAAAEAAAAB",
+        "AAAAAAAAAMxEZXBsb3kgdGhlIHNoYXJlZCB2ZXJpZmllciBmb3IgYGtpbmRgIGlmIG5lZWRlZCBhbmQgcmV0dXJuIGl0cyBhZGRyZXNzLgpWZXJpZmllcnMgYXJlIGRlcGxveWVkIHdoZW4gcmVnaXN0ZXJlZCwgZXhjZXB0IHRoZSBXZWJBdXRobiB2ZXJpZmllcgpnaXZlbiB0byB0aGUgY29uc3RydWN0b3IsIHdoaWNoIGRlcGxveXMgd2l0aCB0aGUgZmlyc3QgYWNjb3VudC4AAAAPZW5zdXJlX3ZlcmlmaWVyAAAAAAEAAAAAAAAABGtpbmQAAAARAAAAAQAAABM=",
+        "AAAAAAAAADVUaGUgdmVyaWZpZXIgV0FTTSBoYXNoIHJlZ2lzdGVyZWQgZm9yIGBraW5kYCwgaWYgYW55LgAAAAAAABZnZXRfdmVyaWZpZXJfa2luZF93YXNtAAAAAAABAAAAAAAAAARraW5kAAAAEQAAAAEAAAPoAAAD7gAAACA=",
+        "AAAAAQAAAHNBbiBhZGRpdGlvbmFsIGNvbnRleHQgcnVsZSBmb3IgYSBuZXcgYWNjb3VudC4gTWlycm9ycyB0aGUgc21hcnQgYWNjb3VudCdzCmBDb250ZXh0UnVsZVBhcmFtc2AgY29uc3RydWN0b3IgYXJndW1lbnQuAAAAAAAAAAARQ29udGV4dFJ1bGVQYXJhbXMAAAAAAAAFAAAAAAAAAAxjb250ZXh0X3R5cGUAAAfQAAAAD0NvbnRleHRSdWxlVHlwZQAAAAAAAAAABG5hbWUAAAAQAAAAAAAAAAhwb2xpY2llcwAAA+wAAAATAAAAAAAAAAAAAAAHc2lnbmVycwAAAAPqAAAH0AAAAAZTaWduZXIAAAAAAAAAAAALdmFsaWRfdW50aWwAAAAD6AAAAAQ=",
+        "AAAAAgAAANdBIHNpZ25lciB0byBpbnN0YWxsIG9uIGEgbmV3IGFjY291bnQuIFRoZSBmYWN0b3J5IHJlc29sdmVzIGVhY2ggc3BlYyB0byBhCmBTaWduZXJgLCBwb2ludGluZyBgRXh0ZXJuYWxgIHNpZ25lcnMgYXQgdGhlIHZlcmlmaWVyIGl0IG1hbmFnZXMgZm9yIHRoZQpzcGVjJ3Mga2luZCwgc28gY2FsbGVycyBjYW5ub3QgYmluZCBhY2NvdW50cyB0byBhcmJpdHJhcnkgdmVyaWZpZXJzLgAAAAAAAAAAClNpZ25lclNwZWMAAAAAAAQAAAABAAAAREEgcGFzc2tleTogdW5jb21wcmVzc2VkIFAtMjU2IHB1YmxpYyBrZXkgYW5kIG9wdGlvbmFsIGNyZWRlbnRpYWwgSUQuAAAACFdlYkF1dGhuAAAAAgAAA+4AAABBAAAD6AAAAA4AAAABAAAAPEEgcmF3IEVkMjU1MTkgcHVibGljIGtleSwgY2hlY2tlZCBieSB0aGUgYGVkMjU1MTlgIHZlcmlmaWVyLgAAAAdFZDI1NTE5AAAAAAEAAAPuAAAAIAAAAAEAAABBQW4gZXhpc3RpbmcgU3RlbGxhciBhY2NvdW50IG9yIGNvbnRyYWN0IHRoYXQgYXV0aG9yaXplcyBuYXRpdmVseS4AAAAAAAAJRGVsZWdhdGVkAAAAAAAAAQAAABMAAAABAAAAQ0tleSBkYXRhIGZvciBhbnkgb3RoZXIgcmVnaXN0ZXJlZCB2ZXJpZmllciBraW5kIChlLmcuIGBzZWNwMjU2azFgKS4AAAAACEV4dGVybmFsAAAAAgAAABEAAAAO",
+        "AAAAAQAAAEJgQ29udGV4dFJ1bGVQYXJhbXNgIHdpdGggc2lnbmVyIHNwZWNzIGluc3RlYWQgb2YgcmVzb2x2ZWQgc2lnbmVycy4AAAAAAAAAAAAPQ29udGV4dFJ1bGVTcGVjAAAAAAUAAAAAAAAADGNvbnRleHRfdHlwZQAAB9AAAAAPQ29udGV4dFJ1bGVUeXBlAAAAAAAAAAAEbmFtZQAAABAAAAAAAAAACHBvbGljaWVzAAAD7AAAABMAAAAAAAAAAAAAAAdzaWduZXJzAAAAA+oAAAfQAAAAClNpZ25lclNwZWMAAAAAAAAAAAALdmFsaWRfdW50aWwAAAAD6AAAAAQ=",
+        "AAAAAQAAAI9GdWxsIGluaXRpYWwgY29uZmlndXJhdGlvbiBvZiBhIHNtYXJ0IGFjY291bnQ6IHRoZSBkZWZhdWx0IHJ1bGUncyBzaWduZXJzCmFuZCBwb2xpY2llcyAocG9saWN5IGFkZHJlc3MgdG8gaW5zdGFsbCBwYXJhbSksIHBsdXMgYW55IGV4dHJhIHJ1bGVzLgAAAAAAAAAADUFjY291bnRDb25maWcAAAAAAAADAAAAAAAAAA1jb250ZXh0X3J1bGVzAAAAAAAD6gAAB9AAAAAPQ29udGV4dFJ1bGVTcGVjAAAAAAAAAAAIcG9saWNpZXMAAAPsAAAAEwAAAAAAAAAAAAAAB3NpZ25lcnMAAAAD6gAAB9AAAAAKU2lnbmVyU3BlYwAA",
+        "AAAAAgAAAHpGZWUgdGhlIGZhY3RvcnkgY2hhcmdlcyBmdW5kZXJzIGZvciBlYWNoIGFjY291bnQgaXQgZGVwbG95cywgaW4gaXRzCmZ1bmRpbmcgdG9rZW4uIFRvcHBpbmcgdXAgYW4gZXhpc3RpbmcgYWNjb3VudCBpcyBmcmVlLgAAAAAAAAAAAAlGZWVDb25maWcAAAAAAAADAAAAAAAAAAAAAAAIRGlzYWJsZWQAAAABAAAAJEEgZml4ZWQgYW1vdW50IHBlciBkZXBsb3llZCBhY2NvdW50LgAAAARGbGF0AAAAAQAAAAsAAAABAAAAO0Jhc2lzIHBvaW50cyBvZiB0aGUgbmV3IGFjY291bnQncyBmdW5kZWQgYW1vdW50ICgxMDAgPSAxJSkuAAAAAANCcHMAAAAAAQAAAAQ=",
+        "AAAAAQAAAJNUb2tlbnMgaGVsZCBieSB0aGUgZmFjdG9yeSB1bnRpbCB0aGUgaG9sZGVyIG9mIHRoZSBjbGFpbSBrZXkgY3JlYXRlcwp0aGVpciBhY2NvdW50LCBvciB1bnRpbCBgZXhwaXJ5X2xlZGdlcmAgcGFzc2VzIGFuZCB0aGUgc2VuZGVyIHRha2VzIHRoZW0KYmFjay4AAAAAAAAAAARHaWZ0AAAABQAAAAAAAAAGYW1vdW50AAAAAAALAAAALUxhc3QgbGVkZ2VyIGF0IHdoaWNoIHRoZSBnaWZ0IGNhbiBiZSBjbGFpbWVkLgAAAAAAAA1leHBpcnlfbGVkZ2VyAAAAAAAABAAAAJpBY2NvdW50IGZlZSBwYWlkIGJ5IHRoZSBzZW5kZXIgaW4gdGhlIGZ1bmRpbmcgdG9rZW4gYXQgZGVwb3NpdC4gSXQKZ29lcyB0byB0aGUgdHJlYXN1cnkgaWYgdGhlIGNsYWltIGRlcGxveXMgdGhlIGFjY291bnQgYW5kIGJhY2sgdG8gdGhlCnNlbmRlciBvdGhlcndpc2UuAAAAAAADZmVlAAAAAAsAAAAAAAAABnNlbmRlcgAAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAAT",
+        "AAAAAgAAAAAAAAAAAAAABUVudHJ5AAAAAAAAAgAAAAEAAAAAAAAAA0tleQAAAAABAAAD7gAAACAAAAABAAAAAAAAAApDcmVkZW50aWFsAAAAAAABAAAD7gAAACA=",
+        "AAAAAQAAAAAAAAAAAAAABFNsb3QAAAACAAAAAAAAAAVlbnRyeQAAAAAAB9AAAAAFRW50cnkAAAAAAAAAAAAACHBvc2l0aW9uAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAABk1lbWJlcgAAAAAAAgAAAAAAAAAHYWNjb3VudAAAAAATAAAAAAAAAAVlbnRyeQAAAAAAB9AAAAAFRW50cnkAAAA=",
+        "AAAABAAAAAAAAAAAAAAADEZhY3RvcnlFcnJvcgAAABAAAAB1QW4gYWNjb3VudCBhbHJlYWR5IGV4aXN0cyBhdCB0aGUgcmVxdWVzdGVkIGFkZHJlc3MsIGJ1dCB0aGUgc3VwcGxpZWQKa2V5IGlzIG5vdCBhIHNpZ25lciBvbiBpdHMgZGVmYXVsdCBjb250ZXh0IHJ1bGUuAAAAAAAADlNpZ25lck1pc21hdGNoAAAAAAABAAAAV0FuIGFjY291bnQgYWxyZWFkeSBleGlzdHMgYXQgdGhlIHJlcXVlc3RlZCBhZGRyZXNzIGFuZCB0aGUgZW50cnlwb2ludApjYW5ub3QgdG9wIGl0IHVwLgAAAAANQWNjb3VudEV4aXN0cwAAAAAAAAIAAAA8QW4gYWNjb3VudCBjb25maWd1cmF0aW9uIGhhcyBubyBzaWduZXJzIG9uIGl0cyBkZWZhdWx0IHJ1bGUuAAAACU5vU2lnbmVycwAAAAAAAAMAAAAzQSBnaWZ0IGVzY3JvdyBpcyBhbHJlYWR5IG9wZW4gdW5kZXIgdGhpcyBjbGFpbSBrZXkuAAAAAApHaWZ0RXhpc3RzAAAAAAAEAAAALE5vIGdpZnQgZXNjcm93IGlzIG9wZW4gdW5kZXIgdGhpcyBjbGFpbSBrZXkuAAAADEdpZnROb3RGb3VuZAAAAAUAAABSVGhlIGdpZnQncyBleHBpcnkgbGVkZ2VyIGhhcyBwYXNzZWQsIG9yIHdvdWxkIGFscmVhZHkgaGF2ZSBwYXNzZWQKd2hlbiBkZXBvc2l0aW5nLgAAAAAAC0dpZnRFeHBpcmVkAAAAAAYAAAA8VGhlIGdpZnQgY2FuIHN0aWxsIGJlIGNsYWltZWQsIHNvIGl0IGNhbm5vdCBiZSByZWZ1bmRlZCB5ZXQuAAAADkdpZnROb3RFeHBpcmVkAAAAAAAHAAAAMkEgYmF0Y2ggaGFzIG1vcmUgZW50cmllcyB0aGFuIG9uZSBjYWxsIG1heSBkZXBsb3kuAAAAAAANQmF0Y2hUb29MYXJnZQAAAAAAAAgAAAA7QSBmbGF0IGZlZSBpcyBuZWdhdGl2ZSBvciBhIGJhc2lzLXBvaW50IGZlZSBleGNlZWRzIDEwIDAwMC4AAAAACkludmFsaWRGZWUAAAAAAAkAAAAxVGhlIGNhbGxlciBpcyBuZWl0aGVyIHRoZSBhZG1pbiBub3IgdGhlIGd1YXJkaWFuLgAAAAAAAAxVbmF1dGhvcml6ZWQAAAAKAAAAO1RoZSBmYWN0b3J5IGlzIHBhdXNlZCBhbmQgZG9lcyBub3QgY3JlYXRlIG9yIGZ1bmQgYWNjb3VudHMuAAAAAAZQYXVzZWQAAAAAAAsAAABAQSBzaWduZXIgc3BlYyBuYW1lcyBhIHZlcmlmaWVyIGtpbmQgdGhlIGZhY3RvcnkgaGFzIG5vIFdBU00gZm9yLgAAAA9Vbmtub3duVmVyaWZpZXIAAAAADAAAAEdUaGUgc2lnbmVyIGhvb2tzIHdlcmUgY2FsbGVkIGJ5IGFuIGFjY291bnQgdGhpcyBmYWN0b3J5IGRpZCBub3QKZGVwbG95LgAAAAAOVW5rbm93bkFjY291bnQAAAAAAA0AAABFVGhlIGtleSBkYXRhIHRvIGluZGV4IGlzIG5vdCBhIHNpZ25lciBvbiB0aGUgYWNjb3VudCdzIGRlZmF1bHQgcnVsZXMuAAAAAAAADlNpZ25lck5vdEZvdW5kAAAAAAAOAAAARVRoZSBrZXkgZGF0YSB0byBkcm9wIGZyb20gdGhlIGluZGV4IGlzIHN0aWxsIGEgc2lnbmVyIG9uIHRoZSBhY2NvdW50LgAAAAAAABFTaWduZXJTdGlsbEFjdGl2ZQAAAAAAAA8AAAAiQSBnaWZ0IGFtb3VudCBpcyB6ZXJvIG9yIG5lZ2F0aXZlLgAAAAAADUludmFsaWRBbW91bnQAAAAAAAAQ",
+        "AAAABQAAAIpQdWJsaXNoZWQgd2hlbmV2ZXIgdGhlIGZhY3RvcnkgZGVwbG95cyBhIG5ldyBzbWFydCBhY2NvdW50LCB3aGljaGV2ZXIKZW50cnlwb2ludCBkZXBsb3llZCBpdC4gVG9wLXVwcyBvZiBleGlzdGluZyBhY2NvdW50cyBkbyBub3QgZW1pdCBpdC4AAAAAAAAAAAAOQWNjb3VudENyZWF0ZWQAAAAAAAEAAAAPYWNjb3VudF9jcmVhdGVkAAAAAAUAAAAAAAAABmZ1bmRlcgAAAAAAEwAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAB9Rmlyc3Qgc2lnbmVyIG9mIHRoZSBhY2NvdW50J3MgZGVmYXVsdCBydWxlOiBhIHBhc3NrZXkgb3Igb3RoZXIKYEV4dGVybmFsYCBzaWduZXIgd2l0aCBpdHMgdmVyaWZpZXIsIG9yIGEgYERlbGVnYXRlZGAgYWRkcmVzcy4AAAAAAAAGc2lnbmVyAAAAAAfQAAAABlNpZ25lcgAAAAAAAAAAAD1BbW91bnQgb2YgdGhlIGZhY3RvcnkncyBmdW5kaW5nIHRva2VuIG1vdmVkIGludG8gdGhlIGFjY291bnQuAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAAAAAADGFjY291bnRfd2FzbQAAA+4AAAAgAAAAAAAAAAI=",
+        "AAAABQAAACxQdWJsaXNoZWQgd2hlbiBhIHNlbmRlciBvcGVucyBhIGdpZnQgZXNjcm93LgAAAAAAAAANR2lmdERlcG9zaXRlZAAAAAAAAAEAAAAOZ2lmdF9kZXBvc2l0ZWQAAAAAAAUAAAAAAAAABnNlbmRlcgAAAAAAEwAAAAEAAAAAAAAACWNsYWltX2tleQAAAAAAA+4AAAAgAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAAAAAANZXhwaXJ5X2xlZGdlcgAAAAAAAAQAAAAAAAAAAg==",
+        "AAAABQAAADhQdWJsaXNoZWQgd2hlbiBhIGdpZnQgaXMgY2xhaW1lZCBpbnRvIGEgcGFzc2tleSBhY2NvdW50LgAAAAAAAAALR2lmdENsYWltZWQAAAAAAQAAAAxnaWZ0X2NsYWltZWQAAAAEAAAAAAAAAAljbGFpbV9rZXkAAAAAAAPuAAAAIAAAAAEAAAAAAAAAB2FjY291bnQAAAAAEwAAAAEAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAAAAAABmFtb3VudAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAADlQdWJsaXNoZWQgd2hlbiBhbiBleHBpcmVkIGdpZnQgaXMgcmV0dXJuZWQgdG8gaXRzIHNlbmRlci4AAAAAAAAAAAAADEdpZnRSZWZ1bmRlZAAAAAEAAAANZ2lmdF9yZWZ1bmRlZAAAAAAAAAQAAAAAAAAACWNsYWltX2tleQAAAAAAA+4AAAAgAAAAAQAAAAAAAAAGc2VuZGVyAAAAAAATAAAAAQAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAAAAAAGYW1vdW50AAAAAAALAAAAAAAAAAI=",
         "AAAABQAAADlFdmVudCBlbWl0dGVkIHdoZW4gYSBzaW1wbGUgdGhyZXNob2xkIHBvbGljeSBpcyBlbmZvcmNlZC4AAAAAAAAAAAAAFFNpbXBsZVBvbGljeUVuZm9yY2VkAAAAAQAAABZzaW1wbGVfcG9saWN5X2VuZm9yY2VkAAAAAAAEAAAAAAAAAA1zbWFydF9hY2NvdW50AAAAAAAAEwAAAAEAAAAAAAAAB2NvbnRleHQAAAAH0AAAAAdDb250ZXh0AAAAAAAAAAAAAAAAD2NvbnRleHRfcnVsZV9pZAAAAAAEAAAAAAAAAAAAAAAVYXV0aGVudGljYXRlZF9zaWduZXJzAAAAAAAD6gAAB9AAAAAGU2lnbmVyAAAAAAAAAAAAAg==",
         "AAAAAQAAADhJbnN0YWxsYXRpb24gcGFyYW1ldGVycyBmb3IgdGhlIHNpbXBsZSB0aHJlc2hvbGQgcG9saWN5LgAAAAAAAAAcU2ltcGxlVGhyZXNob2xkQWNjb3VudFBhcmFtcwAAAAEAAAA5VGhlIG1pbmltdW0gbnVtYmVyIG9mIHNpZ25lcnMgcmVxdWlyZWQgZm9yIGF1dGhvcml6YXRpb24uAAAAAAAACXRocmVzaG9sZAAAAAAAAAQ=",
         "AAAABAAAADNFcnJvciBjb2RlcyBmb3Igc2ltcGxlIHRocmVzaG9sZCBwb2xpY3kgb3BlcmF0aW9ucy4AAAAAAAAAABRTaW1wbGVUaHJlc2hvbGRFcnJvcgAAAAQAAABEVGhlIHNtYXJ0IGFjY291bnQgZG9lcyBub3QgaGF2ZSBhIHNpbXBsZSB0aHJlc2hvbGQgcG9saWN5IGluc3RhbGxlZC4AAAAYU21hcnRBY2NvdW50Tm90SW5zdGFsbGVkAAAMgAAAAD9XaGVuIHRocmVzaG9sZCBpcyAwIG9yIGV4Y2VlZHMgdGhlIG51bWJlciBvZiBhdmFpbGFibGUgc2lnbmVycy4AAAAAEEludmFsaWRUaHJlc2hvbGQAAAyBAAAALlRoZSB0cmFuc2FjdGlvbiBpcyBub3QgYWxsb3dlZCBieSB0aGlzIHBvbGljeS4AAAAAAApOb3RBbGxvd2VkAAAAAAyCAAAAQlRoZSBjb250ZXh0IHJ1bGUgZm9yIHRoZSBzbWFydCBhY2NvdW50IGhhcyBiZWVuIGFscmVhZHkgaW5zdGFsbGVkLgAAAAAAEEFscmVhZHlJbnN0YWxsZWQAAAyD",
@@ -485,6 +1257,50 @@ export class Client extends ContractClient {
   }
   public readonly fromJSON = {
     create_account: this.txFromJSON<string>,
+    create_account_with_assets: this.txFromJSON<string>,
+    create_account_with_config: this.txFromJSON<string>,
+    create_accounts: this.txFromJSON<Array<string>>,
     get_c_address: this.txFromJSON<string>,
+    predict_address: this.txFromJSON<string>,
+    create_account_for_key: this.txFromJSON<string>,
+    create_account_sponsored: this.txFromJSON<string>,
+    deposit_gift: this.txFromJSON<null>,
+    claim_gift: this.txFromJSON<string>,
+    gift_claim_payload: this.txFromJSON<Buffer>,
+    refund_gift: this.txFromJSON<null>,
+    get_gift: this.txFromJSON<Option<Gift>>,
+    get_account_indices: this.txFromJSON<Array<u32>>,
+    lookup_by_key: this.txFromJSON<Array<string>>,
+    lookup_by_credential: this.txFromJSON<Array<string>>,
+    register_signer: this.txFromJSON<null>,
+    unregister_signer: this.txFromJSON<null>,
+    set_account_wasm: this.txFromJSON<u32>,
+    set_verifier_wasm: this.txFromJSON<u32>,
+    set_fee: this.txFromJSON<null>,
+    set_fee_exempt: this.txFromJSON<null>,
+    set_guardian: this.txFromJSON<null>,
+    pause: this.txFromJSON<null>,
+    unpause: this.txFromJSON<null>,
+    is_paused: this.txFromJSON<boolean>,
+    get_guardian: this.txFromJSON<Option<string>>,
+    get_fee: this.txFromJSON<FeeConfig>,
+    get_treasury: this.txFromJSON<Option<string>>,
+    is_fee_exempt: this.txFromJSON<boolean>,
+    funding_token: this.txFromJSON<string>,
+    get_admin: this.txFromJSON<string>,
+    get_account_wasm: this.txFromJSON<Buffer>,
+    get_account_wasm_version: this.txFromJSON<u32>,
+    get_account_wasm_at: this.txFromJSON<Option<Buffer>>,
+    is_approved_account_wasm: this.txFromJSON<boolean>,
+    get_verifier_wasm: this.txFromJSON<Buffer>,
+    get_verifier_wasm_version: this.txFromJSON<u32>,
+    get_verifier_wasm_at: this.txFromJSON<Option<Buffer>>,
+    register_verifier: this.txFromJSON<null>,
+    remove_verifier: this.txFromJSON<null>,
+    get_verifier_kinds: this.txFromJSON<Array<string>>,
+    get_verifier_address: this.txFromJSON<string>,
+    is_verifier_deployed: this.txFromJSON<boolean>,
+    ensure_verifier: this.txFromJSON<string>,
+    get_verifier_kind_wasm: this.txFromJSON<Option<Buffer>>,
   };
 }